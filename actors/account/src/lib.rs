@@ -34,6 +34,10 @@ pub enum Method {
     PubkeyAddress = 2,
     AuthenticateMessage = 3,
     UniversalReceiverHook = frc42_dispatch::method_hash!("Receive"),
+    // Read-only methods also exported with FRC-42 numbers, for calls from outside the builtin
+    // actors.
+    PubkeyAddressExported = frc42_dispatch::method_hash!("PubkeyAddress"),
+    AuthenticateMessageExported = frc42_dispatch::method_hash!("AuthenticateMessage"),
 }
 
 /// Account Actor
@@ -114,11 +118,11 @@ impl ActorCode for Actor {
                 Self::constructor(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::default())
             }
-            Some(Method::PubkeyAddress) => {
+            Some(Method::PubkeyAddress) | Some(Method::PubkeyAddressExported) => {
                 let addr = Self::pubkey_address(rt)?;
                 Ok(RawBytes::serialize(addr)?)
             }
-            Some(Method::AuthenticateMessage) => {
+            Some(Method::AuthenticateMessage) | Some(Method::AuthenticateMessageExported) => {
                 Self::authenticate_message(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::default())
             }
@@ -57,6 +57,28 @@ fn construction() {
     construct(Address::new_actor(&[1, 2, 3]), ExitCode::USR_ILLEGAL_ARGUMENT);
 }
 
+#[test]
+fn construction_forbidden_in_read_only_mode() {
+    let mut rt = MockRuntime {
+        receiver: Address::new_id(100),
+        caller: SYSTEM_ACTOR_ADDR,
+        caller_type: *SYSTEM_ACTOR_CODE_ID,
+        read_only: true,
+        ..Default::default()
+    };
+    rt.expect_validate_caller_addr(vec![SYSTEM_ACTOR_ADDR]);
+    let addr = Address::new_bls(&[1; fvm_shared::address::BLS_PUB_LEN]).unwrap();
+    expect_abort_contains_message(
+        ExitCode::USR_FORBIDDEN,
+        "read-only",
+        rt.call::<AccountActor>(
+            Method::Constructor as MethodNum,
+            &RawBytes::serialize(addr).unwrap(),
+        ),
+    );
+    rt.verify();
+}
+
 #[test]
 fn token_receiver() {
     let mut rt = MockRuntime {
@@ -83,6 +105,32 @@ fn token_receiver() {
     assert_eq!(RawBytes::default(), ret.unwrap());
 }
 
+#[test]
+fn pubkey_address_is_exported() {
+    let mut rt = MockRuntime {
+        receiver: Address::new_id(100),
+        caller: SYSTEM_ACTOR_ADDR,
+        caller_type: *SYSTEM_ACTOR_CODE_ID,
+        ..Default::default()
+    };
+    rt.expect_validate_caller_addr(vec![SYSTEM_ACTOR_ADDR]);
+    let addr = Address::new_secp256k1(&[2; fvm_shared::address::SECP_PUB_LEN]).unwrap();
+    rt.call::<AccountActor>(Method::Constructor as MethodNum, &RawBytes::serialize(&addr).unwrap())
+        .unwrap();
+
+    // Any caller may fetch the key address through the exported method number.
+    rt.set_caller(*MULTISIG_ACTOR_CODE_ID, Address::new_id(1000));
+    rt.expect_validate_caller_any();
+    let ret: Address = rt
+        .call::<AccountActor>(Method::PubkeyAddressExported as MethodNum, &RawBytes::default())
+        .unwrap()
+        .deserialize()
+        .unwrap();
+    rt.verify();
+    assert_eq!(addr, ret);
+    check_state(&rt);
+}
+
 fn check_state(rt: &MockRuntime) {
     let test_address = Address::new_id(1000);
     let (_, acc) = check_state_invariants(&rt.get_state(), &test_address);
@@ -131,5 +179,18 @@ fn authenticate_message() {
         rt.call::<AccountActor>(3, &params).unwrap_err().exit_code()
     );
 
+    // The same check is exported with an FRC-42 method number.
+    rt.expect_validate_caller_any();
+    rt.expect_verify_signature(ExpectedVerifySig {
+        sig: Signature::new_secp256k1(vec![]),
+        signer: addr,
+        plaintext: vec![],
+        result: Ok(()),
+    });
+    assert_eq!(
+        RawBytes::default(),
+        rt.call::<AccountActor>(Method::AuthenticateMessageExported as MethodNum, &params).unwrap()
+    );
+
     rt.verify();
 }
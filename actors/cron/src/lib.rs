@@ -5,10 +5,11 @@ use fil_actors_runtime::runtime::{ActorCode, Runtime};
 use fil_actors_runtime::{actor_error, cbor, ActorError, SYSTEM_ACTOR_ADDR};
 
 use fvm_ipld_encoding::tuple::*;
-use fvm_ipld_encoding::RawBytes;
+use fvm_ipld_encoding::{Cbor, RawBytes};
+use fvm_shared::address::Protocol;
 use fvm_shared::econ::TokenAmount;
 
-use fvm_shared::{MethodNum, METHOD_CONSTRUCTOR};
+use fvm_shared::{MethodNum, METHOD_CONSTRUCTOR, METHOD_SEND};
 use num_derive::FromPrimitive;
 use num_traits::{FromPrimitive, Zero};
 
@@ -28,6 +29,10 @@ fil_actors_runtime::wasm_trampoline!(Actor);
 pub enum Method {
     Constructor = METHOD_CONSTRUCTOR,
     EpochTick = 2,
+    AddEntry = 3,
+    RemoveEntry = 4,
+    // Read-only methods exported with FRC-42 numbers.
+    ListEntries = frc42_dispatch::method_hash!("ListEntries"),
 }
 
 /// Constructor parameters for Cron actor, contains entries
@@ -38,6 +43,22 @@ pub struct ConstructorParams {
     pub entries: Vec<Entry>,
 }
 
+/// Parameters for adding or removing a single cron entry.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct EntryParams {
+    pub entry: Entry,
+}
+
+impl Cbor for EntryParams {}
+
+/// Return value of ListEntries.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct ListEntriesReturn {
+    pub entries: Vec<Entry>,
+}
+
+impl Cbor for ListEntriesReturn {}
+
 /// Cron actor
 pub struct Actor;
 impl Actor {
@@ -72,6 +93,67 @@ impl Actor {
         }
         Ok(())
     }
+
+    /// Registers an additional entry to be called on every epoch tick.
+    /// Only the system actor may add entries, e.g. when installing new actors in an upgrade.
+    fn add_entry(rt: &mut impl Runtime, params: EntryParams) -> Result<(), ActorError> {
+        rt.validate_immediate_caller_is(std::iter::once(&SYSTEM_ACTOR_ADDR))?;
+
+        let entry = params.entry;
+        if entry.receiver.protocol() != Protocol::ID {
+            return Err(actor_error!(
+                illegal_argument,
+                "cron entry receiver {} must be an ID address",
+                entry.receiver
+            ));
+        }
+        if entry.method_num == METHOD_SEND || entry.method_num == METHOD_CONSTRUCTOR {
+            return Err(actor_error!(
+                illegal_argument,
+                "invalid cron entry method number {}",
+                entry.method_num
+            ));
+        }
+        rt.transaction(|st: &mut State, _| {
+            if st.entries.contains(&entry) {
+                return Err(actor_error!(
+                    illegal_argument,
+                    "cron entry for {} method {} already registered",
+                    entry.receiver,
+                    entry.method_num
+                ));
+            }
+            st.entries.push(entry);
+            Ok(())
+        })
+    }
+
+    /// Removes a previously registered entry. Only the system actor may remove entries.
+    fn remove_entry(rt: &mut impl Runtime, params: EntryParams) -> Result<(), ActorError> {
+        rt.validate_immediate_caller_is(std::iter::once(&SYSTEM_ACTOR_ADDR))?;
+
+        let entry = params.entry;
+        rt.transaction(|st: &mut State, _| {
+            let idx = st.entries.iter().position(|e| e == &entry).ok_or_else(|| {
+                actor_error!(
+                    not_found,
+                    "no cron entry for {} method {}",
+                    entry.receiver,
+                    entry.method_num
+                )
+            })?;
+            // Preserve the order in which the remaining entries are called.
+            st.entries.remove(idx);
+            Ok(())
+        })
+    }
+
+    /// Returns the entries called on every epoch tick, in the order they are called.
+    fn list_entries(rt: &mut impl Runtime) -> Result<ListEntriesReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        Ok(ListEntriesReturn { entries: st.entries })
+    }
 }
 
 impl ActorCode for Actor {
@@ -92,6 +174,18 @@ impl ActorCode for Actor {
                 Self::epoch_tick(rt)?;
                 Ok(RawBytes::default())
             }
+            Some(Method::AddEntry) => {
+                Self::add_entry(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::default())
+            }
+            Some(Method::RemoveEntry) => {
+                Self::remove_entry(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::default())
+            }
+            Some(Method::ListEntries) => {
+                let ret = Self::list_entries(rt)?;
+                Ok(RawBytes::serialize(ret)?)
+            }
             None => Err(actor_error!(unhandled_message; "Invalid method")),
         }
     }
@@ -2,13 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0, MIT
 
 use fil_actor_cron::testing::check_state_invariants;
-use fil_actor_cron::{Actor as CronActor, ConstructorParams, Entry, State};
+use fil_actor_cron::{
+    Actor as CronActor, ConstructorParams, Entry, EntryParams, ListEntriesReturn, Method, State,
+};
 use fil_actors_runtime::test_utils::*;
-use fil_actors_runtime::SYSTEM_ACTOR_ADDR;
+use fil_actors_runtime::{ActorError, SYSTEM_ACTOR_ADDR};
 use fvm_ipld_encoding::RawBytes;
 use fvm_shared::address::Address;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::ExitCode;
+use fvm_shared::METHOD_SEND;
 use num_traits::Zero;
 
 fn check_state(rt: &MockRuntime) {
@@ -113,6 +116,65 @@ fn epoch_tick_with_entries() {
     epoch_tick_and_verify(&mut rt);
 }
 
+#[test]
+fn add_and_remove_entries() {
+    let mut rt = construct_runtime();
+
+    let entry1 = Entry { receiver: Address::new_id(1001), method_num: 1001 };
+    let entry2 = Entry { receiver: Address::new_id(1002), method_num: 1002 };
+    let entry3 = Entry { receiver: Address::new_id(1003), method_num: 1003 };
+    construct_and_verify(&mut rt, &ConstructorParams { entries: vec![entry1.clone()] });
+
+    add_entry(&mut rt, &entry2).unwrap();
+    add_entry(&mut rt, &entry3).unwrap();
+    assert_eq!(vec![entry1.clone(), entry2.clone(), entry3.clone()], list_entries(&mut rt));
+
+    // Removal keeps the call order of the remaining entries.
+    remove_entry(&mut rt, &entry2).unwrap();
+    assert_eq!(vec![entry1, entry3], list_entries(&mut rt));
+    check_state(&rt);
+}
+
+#[test]
+fn add_entry_rejects_invalid_entries() {
+    let mut rt = construct_runtime();
+    let entry = Entry { receiver: Address::new_id(1001), method_num: 1001 };
+    construct_and_verify(&mut rt, &ConstructorParams { entries: vec![entry.clone()] });
+
+    expect_abort(ExitCode::USR_ILLEGAL_ARGUMENT, add_entry(&mut rt, &entry));
+    expect_abort(
+        ExitCode::USR_ILLEGAL_ARGUMENT,
+        add_entry(&mut rt, &Entry { receiver: Address::new_id(1002), method_num: METHOD_SEND }),
+    );
+    expect_abort(
+        ExitCode::USR_ILLEGAL_ARGUMENT,
+        add_entry(&mut rt, &Entry { receiver: Address::new_actor(b"cron"), method_num: 1002 }),
+    );
+    assert_eq!(vec![entry], list_entries(&mut rt));
+}
+
+#[test]
+fn remove_missing_entry_fails() {
+    let mut rt = construct_runtime();
+    let entry = Entry { receiver: Address::new_id(1001), method_num: 1001 };
+    construct_and_verify(&mut rt, &ConstructorParams { entries: vec![entry] });
+
+    let missing = Entry { receiver: Address::new_id(1001), method_num: 1002 };
+    expect_abort(ExitCode::USR_NOT_FOUND, remove_entry(&mut rt, &missing));
+}
+
+#[test]
+fn only_system_can_change_entries() {
+    let mut rt = construct_runtime();
+    construct_and_verify(&mut rt, &ConstructorParams { entries: vec![] });
+
+    let entry = Entry { receiver: Address::new_id(1001), method_num: 1001 };
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(1234));
+    expect_abort(ExitCode::USR_FORBIDDEN, add_entry(&mut rt, &entry));
+    expect_abort(ExitCode::USR_FORBIDDEN, remove_entry(&mut rt, &entry));
+    assert_eq!(Vec::<Entry>::new(), list_entries(&mut rt));
+}
+
 fn construct_and_verify(rt: &mut MockRuntime, params: &ConstructorParams) {
     rt.expect_validate_caller_addr(vec![SYSTEM_ACTOR_ADDR]);
     let ret = rt.call::<CronActor>(1, &RawBytes::serialize(&params).unwrap()).unwrap();
@@ -127,3 +189,31 @@ fn epoch_tick_and_verify(rt: &mut MockRuntime) {
     rt.verify();
     check_state(rt);
 }
+
+fn add_entry(rt: &mut MockRuntime, entry: &Entry) -> Result<RawBytes, ActorError> {
+    rt.expect_validate_caller_addr(vec![SYSTEM_ACTOR_ADDR]);
+    let params = EntryParams { entry: entry.clone() };
+    let ret = rt.call::<CronActor>(Method::AddEntry as u64, &RawBytes::serialize(params).unwrap());
+    rt.verify();
+    ret
+}
+
+fn remove_entry(rt: &mut MockRuntime, entry: &Entry) -> Result<RawBytes, ActorError> {
+    rt.expect_validate_caller_addr(vec![SYSTEM_ACTOR_ADDR]);
+    let params = EntryParams { entry: entry.clone() };
+    let ret =
+        rt.call::<CronActor>(Method::RemoveEntry as u64, &RawBytes::serialize(params).unwrap());
+    rt.verify();
+    ret
+}
+
+fn list_entries(rt: &mut MockRuntime) -> Vec<Entry> {
+    rt.expect_validate_caller_any();
+    let ret: ListEntriesReturn = rt
+        .call::<CronActor>(Method::ListEntries as u64, &RawBytes::default())
+        .unwrap()
+        .deserialize()
+        .unwrap();
+    rt.verify();
+    ret.entries
+}
@@ -65,6 +65,11 @@ pub enum Method {
     Burn = 19,
     BurnFrom = 20,
     Allowance = 21,
+    // Read-only token standard methods also exported with FRC-42 numbers, for calls from
+    // outside the builtin actors.
+    TotalSupplyExported = frc42_dispatch::method_hash!("TotalSupply"),
+    BalanceExported = frc42_dispatch::method_hash!("Balance"),
+    AllowanceExported = frc42_dispatch::method_hash!("Allowance"),
 }
 
 pub struct Actor;
@@ -473,11 +478,11 @@ impl ActorCode for Actor {
                 let ret = Self::symbol(rt)?;
                 serialize(&ret, "symbol result")
             }
-            Some(Method::TotalSupply) => {
+            Some(Method::TotalSupply) | Some(Method::TotalSupplyExported) => {
                 let ret = Self::total_supply(rt, cbor::deserialize_params(params)?)?;
                 serialize(&ret, "total_supply result")
             }
-            Some(Method::BalanceOf) => {
+            Some(Method::BalanceOf) | Some(Method::BalanceExported) => {
                 let ret = Self::balance_of(rt, cbor::deserialize_params(params)?)?;
                 serialize(&ret, "balance_of result")
             }
@@ -509,7 +514,7 @@ impl ActorCode for Actor {
                 let ret = Self::burn_from(rt, cbor::deserialize_params(params)?)?;
                 serialize(&ret, "burn_from result")
             }
-            Some(Method::Allowance) => {
+            Some(Method::Allowance) | Some(Method::AllowanceExported) => {
                 let ret = Self::allowance(rt, cbor::deserialize_params(params)?)?;
                 serialize(&ret, "allowance result")
             }
@@ -224,6 +224,62 @@ mod destroy {
     }
 }
 
+mod exported_getters {
+    use frc46_token::token::types::GetAllowanceParams;
+    use fvm_shared::address::Address;
+    use fvm_shared::econ::TokenAmount;
+    use fvm_shared::MethodNum;
+
+    use fil_actor_datacap::{Actor, Method, INFINITE_ALLOWANCE};
+    use fil_actors_runtime::cbor::serialize;
+    use fil_actors_runtime::test_utils::ACCOUNT_ACTOR_CODE_ID;
+    use fvm_ipld_encoding::RawBytes;
+
+    use crate::*;
+
+    #[test]
+    fn getters_callable_by_exported_number() {
+        let (mut rt, h) = make_harness();
+        let amt = TokenAmount::from_whole(42);
+        h.mint(&mut rt, &*ALICE, &amt, vec![*BOB]).unwrap();
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, *CARLA);
+
+        let supply =
+            call_getter(&mut rt, Method::TotalSupplyExported, serialize(&(), "params").unwrap());
+        assert_eq!(amt, supply);
+
+        let balance =
+            call_getter(&mut rt, Method::BalanceExported, serialize(&*ALICE, "params").unwrap());
+        assert_eq!(amt, balance);
+
+        let params = GetAllowanceParams { owner: *ALICE, operator: *BOB };
+        let allowance =
+            call_getter(&mut rt, Method::AllowanceExported, serialize(&params, "params").unwrap());
+        assert_eq!(*INFINITE_ALLOWANCE, allowance);
+
+        // The exported numbers return the same as the private ones.
+        let private = call_getter(
+            &mut rt,
+            Method::BalanceOf,
+            serialize(&Address::new_id(999), "params").unwrap(),
+        );
+        let exported = call_getter(
+            &mut rt,
+            Method::BalanceExported,
+            serialize(&Address::new_id(999), "params").unwrap(),
+        );
+        assert_eq!(private, exported);
+        h.check_state(&rt);
+    }
+
+    fn call_getter(rt: &mut MockRuntime, method: Method, params: RawBytes) -> TokenAmount {
+        rt.expect_validate_caller_any();
+        let ret = rt.call::<Actor>(method as MethodNum, &params).unwrap().deserialize().unwrap();
+        rt.verify();
+        ret
+    }
+}
+
 fn make_harness() -> (MockRuntime, Harness) {
     let mut rt = new_runtime();
     let h = Harness { governor: VERIFIED_REGISTRY_ACTOR_ADDR };
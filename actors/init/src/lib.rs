@@ -27,6 +27,8 @@ fil_actors_runtime::wasm_trampoline!(Actor);
 pub enum Method {
     Constructor = METHOD_CONSTRUCTOR,
     Exec = 2,
+    // Read-only methods exported with FRC-42 numbers.
+    ResolveAddress = frc42_dispatch::method_hash!("ResolveAddress"),
 }
 
 /// Init actor
@@ -42,6 +44,23 @@ impl Actor {
         Ok(())
     }
 
+    /// Resolves an address to the ID address of the actor it refers to.
+    /// Returns None if no actor has been assigned to the address or, for an ID address,
+    /// if no actor exists with that ID.
+    pub fn resolve_address(
+        rt: &mut impl Runtime,
+        address: Address,
+    ) -> Result<Option<Address>, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        // The address map only holds robust addresses, so check ID addresses against the actors
+        // which exist.
+        if let Ok(id) = address.id() {
+            return Ok(rt.get_actor_code_cid(&id).map(|_| address));
+        }
+        let st: State = rt.state()?;
+        st.resolve_address(rt.store(), &address)
+    }
+
     /// Exec init actor
     pub fn exec(rt: &mut impl Runtime, params: ExecParams) -> Result<ExecReturn, ActorError> {
         rt.validate_immediate_caller_accept_any()?;
@@ -78,7 +97,7 @@ impl Actor {
         })?;
 
         // Create an empty actor
-        rt.create_actor(params.code_cid, id_address)?;
+        rt.create_actor(params.code_cid, id_address, None)?;
 
         // Invoke constructor
         rt.send(
@@ -111,6 +130,10 @@ impl ActorCode for Actor {
                 let res = Self::exec(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::serialize(res)?)
             }
+            Some(Method::ResolveAddress) => {
+                let res = Self::resolve_address(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             None => Err(actor_error!(unhandled_message; "Invalid method")),
         }
     }
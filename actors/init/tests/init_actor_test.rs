@@ -66,7 +66,7 @@ fn repeated_robust_address() {
         // Next id
         let expected_id = 100;
         let expected_id_addr = Address::new_id(expected_id);
-        rt.expect_create_actor(*MULTISIG_ACTOR_CODE_ID, expected_id);
+        rt.expect_create_actor(*MULTISIG_ACTOR_CODE_ID, expected_id, None);
 
         // Expect a send to the multisig actor constructor
         rt.expect_send(
@@ -125,7 +125,7 @@ fn create_2_payment_channels() {
 
         let expected_id = 100 + n;
         let expected_id_addr = Address::new_id(expected_id);
-        rt.expect_create_actor(*PAYCH_ACTOR_CODE_ID, expected_id);
+        rt.expect_create_actor(*PAYCH_ACTOR_CODE_ID, expected_id, None);
 
         let fake_params = ConstructorParams { network_name: String::from("fake_param") };
 
@@ -170,7 +170,7 @@ fn create_storage_miner() {
 
     let expected_id = 100;
     let expected_id_addr = Address::new_id(expected_id);
-    rt.expect_create_actor(*MINER_ACTOR_CODE_ID, expected_id);
+    rt.expect_create_actor(*MINER_ACTOR_CODE_ID, expected_id, None);
 
     let fake_params = ConstructorParams { network_name: String::from("fake_param") };
 
@@ -221,7 +221,7 @@ fn create_multisig_actor() {
     // Next id
     let expected_id = 100;
     let expected_id_addr = Address::new_id(expected_id);
-    rt.expect_create_actor(*MULTISIG_ACTOR_CODE_ID, expected_id);
+    rt.expect_create_actor(*MULTISIG_ACTOR_CODE_ID, expected_id, None);
 
     let fake_params = ConstructorParams { network_name: String::from("fake_param") };
     // Expect a send to the multisig actor constructor
@@ -257,7 +257,7 @@ fn sending_constructor_failure() {
     // Create the next id address
     let expected_id = 100;
     let expected_id_addr = Address::new_id(expected_id);
-    rt.expect_create_actor(*MINER_ACTOR_CODE_ID, expected_id);
+    rt.expect_create_actor(*MINER_ACTOR_CODE_ID, expected_id, None);
 
     let fake_params = ConstructorParams { network_name: String::from("fake_param") };
     rt.expect_send(
@@ -287,6 +287,39 @@ fn sending_constructor_failure() {
     check_state(&rt);
 }
 
+#[test]
+fn resolve_address() {
+    let mut rt = construct_runtime();
+    construct_and_verify(&mut rt);
+
+    let robust_address = Address::new_actor(b"paych");
+    let fake_params = ConstructorParams { network_name: String::from("fake_param") };
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(1234));
+    rt.new_actor_addr = Some(robust_address);
+    let expected_id_addr = Address::new_id(100);
+    rt.expect_create_actor(*PAYCH_ACTOR_CODE_ID, 100, None);
+    rt.expect_send(
+        expected_id_addr,
+        METHOD_CONSTRUCTOR,
+        RawBytes::serialize(&fake_params).unwrap(),
+        TokenAmount::zero(),
+        RawBytes::default(),
+        ExitCode::OK,
+    );
+    exec_and_verify(&mut rt, *PAYCH_ACTOR_CODE_ID, &fake_params).unwrap();
+    // The mock runtime doesn't record the actors it creates.
+    rt.set_address_actor_type(expected_id_addr, *PAYCH_ACTOR_CODE_ID);
+
+    assert_eq!(Some(expected_id_addr), resolve_and_verify(&mut rt, &robust_address));
+    // ID addresses of existing actors resolve to themselves.
+    assert_eq!(Some(expected_id_addr), resolve_and_verify(&mut rt, &expected_id_addr));
+    // ID addresses of actors which don't exist don't resolve.
+    assert_eq!(None, resolve_and_verify(&mut rt, &Address::new_id(101)));
+    // Addresses not assigned to any actor don't resolve.
+    assert_eq!(None, resolve_and_verify(&mut rt, &Address::new_actor(b"unknown")));
+    check_state(&rt);
+}
+
 fn construct_and_verify(rt: &mut MockRuntime) {
     rt.expect_validate_caller_addr(vec![SYSTEM_ACTOR_ADDR]);
     let params = ConstructorParams { network_name: "mock".to_string() };
@@ -327,3 +360,12 @@ where
     check_state(rt);
     ret
 }
+
+fn resolve_and_verify(rt: &mut MockRuntime, address: &Address) -> Option<Address> {
+    rt.expect_validate_caller_any();
+    let ret = rt
+        .call::<InitActor>(Method::ResolveAddress as u64, &RawBytes::serialize(address).unwrap())
+        .unwrap();
+    rt.verify();
+    ret.deserialize().unwrap()
+}
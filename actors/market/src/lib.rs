@@ -30,7 +30,7 @@ use fil_actors_runtime::cbor::{deserialize, serialize, serialize_vec};
 use fil_actors_runtime::runtime::builtins::Type;
 use fil_actors_runtime::runtime::{ActorCode, Policy, Runtime};
 use fil_actors_runtime::{
-    actor_error, cbor, ActorContext, ActorDowncast, ActorError, AsActorError,
+    actor_error, batch_ops, cbor, ActorContext, ActorDowncast, ActorError, AsActorError,
     BURNT_FUNDS_ACTOR_ADDR, CALLER_TYPES_SIGNABLE, CRON_ACTOR_ADDR, DATACAP_TOKEN_ACTOR_ADDR,
     REWARD_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR, SYSTEM_ACTOR_ADDR, VERIFIED_REGISTRY_ACTOR_ADDR,
 };
@@ -71,6 +71,8 @@ pub enum Method {
     OnMinerSectorsTerminate = 7,
     ComputeDataCommitment = 8,
     CronTick = 9,
+    // Read-only methods exported with FRC-42 numbers.
+    GetDealAllocationId = frc42_dispatch::method_hash!("GetDealAllocationId"),
 }
 
 /// Market Actor
@@ -425,6 +427,8 @@ impl Actor {
                 })?;
             // All storage dealProposals will be added in an atomic transaction; this operation will be unrolled if any of them fails.
             // This should only fail on programmer error because all expected invalid conditions should be filtered in the first set of checks.
+            let mut new_proposals = Vec::with_capacity(valid_deals.len());
+            let mut new_allocations = Vec::new();
             for valid_deal in valid_deals.iter() {
                 msm.lock_client_and_provider_balances(&valid_deal.proposal)?;
 
@@ -434,24 +438,13 @@ impl Actor {
                     .unwrap()
                     .put(valid_deal.cid.to_bytes().into())
                     .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to set pending deal")?;
-                // Allocate a deal ID and store the proposal in the proposals AMT.
+                // Allocate a deal ID; the proposal is stored in the proposals AMT below.
                 let deal_id = msm.generate_storage_deal_id();
-                msm.deal_proposals
-                    .as_mut()
-                    .unwrap()
-                    .set(deal_id, valid_deal.proposal.clone())
-                    .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to set deal")?;
+                new_proposals.push((deal_id, valid_deal.proposal.clone()));
                 // Store verified allocation (if any) in the pending allocation IDs map.
                 // It will be removed when the deal is activated or expires.
                 if valid_deal.allocation != NO_ALLOCATION_ID {
-                    msm.pending_deal_allocation_ids
-                        .as_mut()
-                        .unwrap()
-                        .set(deal_id_key(deal_id), valid_deal.allocation)
-                        .context_code(
-                            ExitCode::USR_ILLEGAL_STATE,
-                            "failed to set deal allocation",
-                        )?;
+                    new_allocations.push((deal_id, valid_deal.allocation));
                 }
 
                 // Randomize the first epoch for when the deal will be processed so an attacker isn't able to
@@ -469,6 +462,21 @@ impl Actor {
                 new_deal_ids.push(deal_id);
             }
 
+            batch_ops::set_many(msm.deal_proposals.as_mut().unwrap(), new_proposals)
+                .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to set deals")?;
+            if let Some(deal_id) = batch_ops::set_many_if_absent(
+                msm.pending_deal_allocation_ids.as_mut().unwrap(),
+                new_allocations,
+            )
+            .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to set deal allocations")?
+            {
+                return Err(actor_error!(
+                    illegal_state,
+                    "allocation already pending for new deal {}",
+                    deal_id
+                ));
+            }
+
             msm.commit_state()?;
             Ok(())
         })?;
@@ -556,9 +564,18 @@ impl Actor {
                     e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load state")
                 })?;
 
+            let mut new_states = Vec::with_capacity(params.deal_ids.len());
+            let mut activated = BTreeSet::new();
             for deal_id in params.deal_ids {
-                // This construction could be replaced with a single "update deal state"
-                // state method, possibly batched over all deal ids at once.
+                // New states are only written after the loop, so a repeated deal ID
+                // must be caught here rather than by the state lookup.
+                if !activated.insert(deal_id) {
+                    return Err(actor_error!(
+                        illegal_argument,
+                        "deal {} already activated",
+                        deal_id
+                    ));
+                }
                 let s = msm
                     .deal_states
                     .as_ref()
@@ -625,23 +642,20 @@ impl Actor {
                         size: proposal.piece_size,
                     })
                 }
-                msm.deal_states
-                    .as_mut()
-                    .unwrap()
-                    .set(
-                        deal_id,
-                        DealState {
-                            sector_start_epoch: curr_epoch,
-                            last_updated_epoch: EPOCH_UNDEFINED,
-                            slash_epoch: EPOCH_UNDEFINED,
-                            verified_claim: allocation,
-                        },
-                    )
-                    .with_context_code(ExitCode::USR_ILLEGAL_STATE, || {
-                        format!("failed to set deal state {}", deal_id)
-                    })?;
+                new_states.push((
+                    deal_id,
+                    DealState {
+                        sector_start_epoch: curr_epoch,
+                        last_updated_epoch: EPOCH_UNDEFINED,
+                        slash_epoch: EPOCH_UNDEFINED,
+                        verified_claim: allocation,
+                    },
+                ));
             }
 
+            batch_ops::set_many(msm.deal_states.as_mut().unwrap(), new_states)
+                .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to set deal states")?;
+
             msm.commit_state()?;
             Ok(())
         })?;
@@ -668,6 +682,7 @@ impl Actor {
                     e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load state")
                 })?;
 
+            let mut slashed_states = Vec::new();
             for id in params.deal_ids {
                 let deal = msm.deal_proposals.as_ref().unwrap().get(id).map_err(|e| {
                     e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to get deal proposal")
@@ -717,21 +732,71 @@ impl Actor {
                 // mark the deal for slashing here. Actual releasing of locked funds for the client
                 // and slashing of provider collateral happens in cron_tick.
                 state.slash_epoch = params.epoch;
-
-                msm.deal_states.as_mut().unwrap().set(id, state).map_err(|e| {
-                    e.downcast_default(
-                        ExitCode::USR_ILLEGAL_STATE,
-                        format!("failed to set deal state ({})", id),
-                    )
-                })?;
+                slashed_states.push((id, state));
             }
 
+            batch_ops::set_many(msm.deal_states.as_mut().unwrap(), slashed_states)
+                .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to set deal states")?;
+
             msm.commit_state()?;
             Ok(())
         })?;
         Ok(())
     }
 
+    /// Returns the verified registry allocation ID of a deal, or NO_ALLOCATION_ID if the deal
+    /// is not verified. Once the deal is activated this is also the ID of the resulting claim.
+    fn get_deal_allocation_id(
+        rt: &mut impl Runtime,
+        params: GetDealAllocationIdParams,
+    ) -> Result<GetDealAllocationIdReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let deal_id = params.id;
+
+        let mut st: State = rt.state()?;
+        let mut msm = st.mutator(rt.store());
+        msm.with_deal_proposals(Permission::ReadOnly)
+            .with_deal_states(Permission::ReadOnly)
+            .with_pending_proposals(Permission::ReadOnly)
+            .build()
+            .map_err(|e| e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load msm"))?;
+
+        msm.deal_proposals
+            .as_ref()
+            .unwrap()
+            .get(deal_id)
+            .with_context_code(ExitCode::USR_ILLEGAL_STATE, || {
+                format!("failed to get deal proposal {}", deal_id)
+            })?
+            .ok_or_else(|| actor_error!(not_found, "no such deal {}", deal_id))?;
+
+        // An activated deal records its allocation in its state, otherwise it's still pending.
+        let state = msm
+            .deal_states
+            .as_ref()
+            .unwrap()
+            .get(deal_id)
+            .with_context_code(ExitCode::USR_ILLEGAL_STATE, || {
+                format!("failed to get deal state {}", deal_id)
+            })?
+            .cloned();
+        let allocation_id = match state {
+            Some(state) => state.verified_claim,
+            None => msm
+                .pending_deal_allocation_ids
+                .as_ref()
+                .unwrap()
+                .get(&deal_id_key(deal_id))
+                .with_context_code(ExitCode::USR_ILLEGAL_STATE, || {
+                    format!("failed to get allocation id for deal {}", deal_id)
+                })?
+                .cloned()
+                .unwrap_or(NO_ALLOCATION_ID),
+        };
+
+        Ok(GetDealAllocationIdReturn { allocation_id })
+    }
+
     fn compute_data_commitment(
         rt: &mut impl Runtime,
         params: ComputeDataCommitmentParams,
@@ -1426,6 +1491,10 @@ impl ActorCode for Actor {
                 Self::cron_tick(rt)?;
                 Ok(RawBytes::default())
             }
+            Some(Method::GetDealAllocationId) => {
+                let res = Self::get_deal_allocation_id(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             None => Err(actor_error!(unhandled_message, "Invalid method")),
         }
     }
@@ -18,7 +18,6 @@ use fvm_shared::clock::{ChainEpoch, EPOCH_UNDEFINED};
 use fvm_shared::deal::DealID;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::ExitCode;
-use fvm_shared::HAMT_BIT_WIDTH;
 use num_traits::Zero;
 
 use super::policy::*;
@@ -79,9 +78,10 @@ impl State {
             .flush()
             .map_err(|e| anyhow!("Failed to create empty states array: {}", e))?;
 
-        let empty_pending_proposals_map = make_empty_map::<_, ()>(store, HAMT_BIT_WIDTH)
-            .flush()
-            .map_err(|e| anyhow!("Failed to create empty pending proposals map state: {}", e))?;
+        let empty_pending_proposals_map =
+            make_empty_map::<_, ()>(store, PENDING_PROPOSALS_HAMT_BITWIDTH).flush().map_err(
+                |e| anyhow!("Failed to create empty pending proposals map state: {}", e),
+            )?;
         let empty_balance_table = BalanceTable::new(store)
             .root()
             .map_err(|e| anyhow!("Failed to create empty balance table map: {}", e))?;
@@ -89,9 +89,11 @@ impl State {
             .root()
             .map_err(|e| anyhow!("Failed to create empty multiset: {}", e))?;
         let empty_pending_deal_allocation_map =
-            make_empty_map::<_, AllocationID>(store, HAMT_BIT_WIDTH).flush().map_err(|e| {
-                anyhow!("Failed to create empty pending deal allocation map: {}", e)
-            })?;
+            make_empty_map::<_, AllocationID>(store, PENDING_ALLOCATIONS_HAMT_BITWIDTH)
+                .flush()
+                .map_err(|e| {
+                    anyhow!("Failed to create empty pending deal allocation map: {}", e)
+                })?;
         Ok(Self {
             proposals: empty_proposals_array,
             states: empty_states_array,
@@ -250,7 +252,7 @@ where
             self.pending_deal_allocation_ids = Some(make_map_with_root_and_bitwidth(
                 &self.st.pending_deal_allocation_ids,
                 self.store,
-                HAMT_BIT_WIDTH,
+                PENDING_ALLOCATIONS_HAMT_BITWIDTH,
             )?);
         }
 
@@ -5,7 +5,6 @@ use std::{
 };
 
 use cid::Cid;
-use fil_actors_runtime::builtin::HAMT_BIT_WIDTH;
 use fil_actors_runtime::{
     make_map_with_root_and_bitwidth, parse_uint_key, MessageAccumulator, SetMultimap,
 };
@@ -20,7 +19,8 @@ use integer_encoding::VarInt;
 use num_traits::Zero;
 
 use crate::{
-    balance_table::BalanceTable, deal_cid, DealArray, DealMetaArray, State, PROPOSALS_AMT_BITWIDTH,
+    balance_table::BalanceTable, deal_cid, DealArray, DealMetaArray, State,
+    PENDING_ALLOCATIONS_HAMT_BITWIDTH, PROPOSALS_AMT_BITWIDTH,
 };
 use crate::{ext::verifreg::AllocationID, NO_ALLOCATION_ID};
 
@@ -148,8 +148,11 @@ pub fn check_state_invariants<BS: Blockstore + Debug>(
 
     let mut pending_allocations = BTreeMap::<DealID, AllocationID>::new();
     let mut alloc_id_to_deal_id = BTreeMap::<AllocationID, DealID>::new();
-    match make_map_with_root_and_bitwidth(&state.pending_deal_allocation_ids, store, HAMT_BIT_WIDTH)
-    {
+    match make_map_with_root_and_bitwidth(
+        &state.pending_deal_allocation_ids,
+        store,
+        PENDING_ALLOCATIONS_HAMT_BITWIDTH,
+    ) {
         Ok(pending_allocations_hamt) => {
             let ret = pending_allocations_hamt.for_each(|key, allocation_id| {
                 let deal_id: u64 = u64::decode_var(key.0.as_slice()).unwrap().0;
@@ -14,6 +14,7 @@ use fvm_shared::deal::DealID;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::piece::PaddedPieceSize;
 use fvm_shared::ActorID;
+use fvm_shared::HAMT_BIT_WIDTH;
 
 use fvm_shared::sector::RegisteredSealProof;
 
@@ -21,6 +22,8 @@ use super::deal::{ClientDealProposal, DealProposal, DealState};
 
 pub const PROPOSALS_AMT_BITWIDTH: u32 = 5;
 pub const STATES_AMT_BITWIDTH: u32 = 6;
+pub const PENDING_PROPOSALS_HAMT_BITWIDTH: u32 = HAMT_BIT_WIDTH;
+pub const PENDING_ALLOCATIONS_HAMT_BITWIDTH: u32 = HAMT_BIT_WIDTH;
 
 #[derive(Serialize_tuple, Deserialize_tuple)]
 pub struct WithdrawBalanceParams {
@@ -136,6 +139,22 @@ pub struct ComputeDataCommitmentReturn {
     pub commds: Vec<Cid>,
 }
 
+#[derive(Serialize_tuple, Deserialize_tuple)]
+#[serde(transparent)]
+pub struct GetDealAllocationIdParams {
+    pub id: DealID,
+}
+
+impl Cbor for GetDealAllocationIdParams {}
+
+#[derive(Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+#[serde(transparent)]
+pub struct GetDealAllocationIdReturn {
+    pub allocation_id: AllocationID,
+}
+
+impl Cbor for GetDealAllocationIdReturn {}
+
 /// A specialization of a array to deals.
 pub type DealArray<'bs, BS> = Array<'bs, DealProposal, BS>;
 
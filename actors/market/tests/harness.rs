@@ -13,10 +13,10 @@ use fil_actor_market::{
     balance_table::BalanceTable, deal_id_key, ext, ext::miner::GetControlAddressesReturnParams,
     gen_rand_next_epoch, testing::check_state_invariants, ActivateDealsParams, ActivateDealsResult,
     Actor as MarketActor, ClientDealProposal, DealArray, DealMetaArray, DealProposal, DealState,
-    Label, Method, OnMinerSectorsTerminateParams, PublishStorageDealsParams,
-    PublishStorageDealsReturn, SectorDeals, State, VerifyDealsForActivationParams,
-    VerifyDealsForActivationReturn, WithdrawBalanceParams, WithdrawBalanceReturn, NO_ALLOCATION_ID,
-    PROPOSALS_AMT_BITWIDTH,
+    GetDealAllocationIdParams, GetDealAllocationIdReturn, Label, Method,
+    OnMinerSectorsTerminateParams, PublishStorageDealsParams, PublishStorageDealsReturn,
+    SectorDeals, State, VerifyDealsForActivationParams, VerifyDealsForActivationReturn,
+    WithdrawBalanceParams, WithdrawBalanceReturn, NO_ALLOCATION_ID, PROPOSALS_AMT_BITWIDTH,
 };
 use fil_actor_power::{CurrentTotalPowerReturn, Method as PowerMethod};
 use fil_actor_reward::Method as RewardMethod;
@@ -315,6 +315,20 @@ pub fn get_pending_deal_allocation(rt: &mut MockRuntime, deal_id: DealID) -> All
     *pending_allocations.get(&deal_id_key(deal_id)).unwrap().unwrap_or(&NO_ALLOCATION_ID)
 }
 
+pub fn get_deal_allocation_id(
+    rt: &mut MockRuntime,
+    deal_id: DealID,
+) -> Result<AllocationID, ActorError> {
+    rt.expect_validate_caller_any();
+    let params = GetDealAllocationIdParams { id: deal_id };
+    let ret = rt.call::<MarketActor>(
+        Method::GetDealAllocationId as u64,
+        &RawBytes::serialize(params).unwrap(),
+    )?;
+    rt.verify();
+    Ok(ret.deserialize::<GetDealAllocationIdReturn>().unwrap().allocation_id)
+}
+
 pub fn get_locked_balance(rt: &mut MockRuntime, addr: Address) -> TokenAmount {
     let st: State = rt.get_state();
     let lt = BalanceTable::from_root(&rt.store, &st.locked_table).unwrap();
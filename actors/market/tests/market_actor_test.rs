@@ -726,6 +726,58 @@ fn simple_deal() {
     check_state(&rt);
 }
 
+#[test]
+fn get_deal_allocation_id_before_and_after_activation() {
+    let start_epoch = 1000;
+    let end_epoch = start_epoch + 200 * EPOCHS_IN_DAY;
+    let publish_epoch = ChainEpoch::from(1);
+
+    let mut rt = setup();
+    rt.set_epoch(publish_epoch);
+    let next_allocation_id = 1;
+
+    let mut deal1 = generate_deal_and_add_funds(
+        &mut rt,
+        CLIENT_ADDR,
+        &MinerAddresses::default(),
+        start_epoch,
+        end_epoch,
+    );
+    deal1.verified_deal = false;
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, WORKER_ADDR);
+    let deal1_id =
+        publish_deals(&mut rt, &MinerAddresses::default(), &[deal1], next_allocation_id)[0];
+
+    let mut deal2 = generate_deal_and_add_funds(
+        &mut rt,
+        CLIENT_ADDR,
+        &MinerAddresses::default(),
+        start_epoch + 1,
+        end_epoch + 1,
+    );
+    deal2.verified_deal = true;
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, WORKER_ADDR);
+    let deal2_id =
+        publish_deals(&mut rt, &MinerAddresses::default(), &[deal2], next_allocation_id)[0];
+
+    // pending deals report the allocation made at publish
+    assert_eq!(NO_ALLOCATION_ID, get_deal_allocation_id(&mut rt, deal1_id).unwrap());
+    assert_eq!(next_allocation_id, get_deal_allocation_id(&mut rt, deal2_id).unwrap());
+
+    // activated deals report the same ID, now recorded in deal state
+    activate_deals(&mut rt, end_epoch + 1, PROVIDER_ADDR, publish_epoch, &[deal1_id, deal2_id]);
+    assert_eq!(NO_ALLOCATION_ID, get_deal_allocation_id(&mut rt, deal1_id).unwrap());
+    assert_eq!(next_allocation_id, get_deal_allocation_id(&mut rt, deal2_id).unwrap());
+
+    expect_abort_contains_message(
+        ExitCode::USR_NOT_FOUND,
+        "no such deal",
+        get_deal_allocation_id(&mut rt, deal2_id + 1),
+    );
+    rt.reset();
+    check_state(&rt);
+}
+
 #[test]
 fn deal_expires() {
     let start_epoch = 100;
@@ -9,7 +9,7 @@ use std::ops::Neg;
 use anyhow::{anyhow, Error};
 use byteorder::{BigEndian, ByteOrder, WriteBytesExt};
 use cid::Cid;
-use fvm_ipld_bitfield::{BitField, Validate};
+use fvm_ipld_bitfield::BitField;
 use fvm_ipld_blockstore::Blockstore;
 use fvm_ipld_encoding::{from_slice, BytesDe, Cbor, CborStore, RawBytes};
 use fvm_shared::address::{Address, Payload, Protocol};
@@ -26,7 +26,7 @@ use fvm_shared::{MethodNum, METHOD_CONSTRUCTOR, METHOD_SEND};
 use log::{error, info, warn};
 use multihash::Code::Blake2b256;
 use num_derive::FromPrimitive;
-use num_traits::{FromPrimitive, Zero};
+use num_traits::{FromPrimitive, Signed, Zero};
 
 pub use beneficiary::*;
 pub use bitfield_queue::*;
@@ -36,6 +36,7 @@ pub use deadline_info::*;
 pub use deadline_state::*;
 pub use deadlines::*;
 pub use expiration_queue::*;
+use fil_actors_runtime::bitfield::validate_bitfield;
 use fil_actors_runtime::cbor::{deserialize, serialize, serialize_vec};
 use fil_actors_runtime::runtime::builtins::Type;
 use fil_actors_runtime::runtime::{ActorCode, DomainSeparationTag, Policy, Runtime};
@@ -123,6 +124,18 @@ pub enum Method {
     ChangeBeneficiary = 30,
     GetBeneficiary = 31,
     ExtendSectorExpiration2 = 32,
+    CancelChangeWorkerAddress = 33,
+    // Read-only methods exported with FRC-42 numbers.
+    GetOnboardingInfo = frc42_dispatch::method_hash!("GetOnboardingInfo"),
+    EstimatePreCommitDeposit = frc42_dispatch::method_hash!("EstimatePreCommitDeposit"),
+    EstimateInitialPledge = frc42_dispatch::method_hash!("EstimateInitialPledge"),
+    GetDeadlineInfo = frc42_dispatch::method_hash!("GetDeadlineInfo"),
+    GetDeadlinePartitionCount = frc42_dispatch::method_hash!("GetDeadlinePartitionCount"),
+    GetPartitionSectors = frc42_dispatch::method_hash!("GetPartitionSectors"),
+    GetTerminationFee = frc42_dispatch::method_hash!("GetTerminationFee"),
+    GetSectorInfo = frc42_dispatch::method_hash!("GetSectorInfo"),
+    // Methods also exported with FRC-42 numbers, for calls from outside the builtin actors.
+    ReportConsensusFaultExported = frc42_dispatch::method_hash!("ReportConsensusFault"),
 }
 
 pub const ERR_BALANCE_INVARIANTS_BROKEN: ExitCode = ExitCode::new(1000);
@@ -269,6 +282,25 @@ impl Actor {
         })
     }
 
+    /// Cancels a pending worker address change before it takes effect.
+    fn cancel_change_worker_address(rt: &mut impl Runtime) -> Result<(), ActorError> {
+        rt.transaction(|state: &mut State, rt| {
+            let mut info = get_miner_info(rt.store(), state)?;
+
+            rt.validate_immediate_caller_is(std::iter::once(&info.owner))?;
+
+            if info.pending_worker_key.take().is_none() {
+                return Err(actor_error!(illegal_argument, "no pending worker key change"));
+            }
+
+            state.save_info(rt.store(), &info).map_err(|e| {
+                e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "could not save miner info")
+            })?;
+
+            Ok(())
+        })
+    }
+
     /// Proposes or confirms a change of owner address.
     /// If invoked by the current owner, proposes a new owner address for confirmation. If the proposed address is the
     /// current owner address, revokes any existing proposal.
@@ -648,9 +680,7 @@ impl Actor {
         rt: &mut impl Runtime,
         params: ProveCommitAggregateParams,
     ) -> Result<(), ActorError> {
-        let sector_numbers = params.sector_numbers.validate().map_err(|e| {
-            actor_error!(illegal_state, "Failed to validate bitfield for aggregated sectors: {}", e)
-        })?;
+        let sector_numbers = &params.sector_numbers;
         let agg_sectors_count = sector_numbers.len();
 
         {
@@ -685,6 +715,15 @@ impl Actor {
         rt.validate_immediate_caller_is(
             info.control_addresses.iter().chain(&[info.worker, info.owner]),
         )?;
+        // Charged only for authorized callers, after the checks above bound the sector count.
+        let max_aggregated_sectors = rt.policy().max_aggregated_sectors;
+        validate_bitfield(
+            rt,
+            sector_numbers,
+            max_aggregated_sectors,
+            MAX_SECTOR_NUMBER,
+            "aggregated sectors",
+        )?;
         let store = rt.store();
         let precommits =
             state.get_all_precommitted_sectors(store, sector_numbers).map_err(|e| {
@@ -2490,6 +2529,14 @@ impl Actor {
                 info.control_addresses.iter().chain(&[info.worker, info.owner]),
             )?;
 
+            let target_deadlines = fr_declaration_deadlines(
+                rt.policy(),
+                state,
+                to_process.deadlines(),
+                rt.curr_epoch(),
+                "fault",
+            )?;
+
             let store = rt.store();
 
             let mut deadlines =
@@ -2500,33 +2547,9 @@ impl Actor {
             })?;
 
             let mut new_fault_power_total = PowerPair::zero();
-            let curr_epoch = rt.curr_epoch();
             for (deadline_idx, partition_map) in to_process.iter() {
                 let policy = rt.policy();
-                let target_deadline = declaration_deadline_info(
-                    policy,
-                    state.current_proving_period_start(policy, curr_epoch),
-                    deadline_idx,
-                    curr_epoch,
-                )
-                .map_err(|e| {
-                    actor_error!(
-                        illegal_argument,
-                        "invalid fault declaration deadline {}: {}",
-                        deadline_idx,
-                        e
-                    )
-                })?;
-
-                validate_fr_declaration_deadline(&target_deadline).map_err(|e| {
-                    actor_error!(
-                        illegal_argument,
-                        "failed fault declaration at deadline {}: {}",
-                        deadline_idx,
-                        e
-                    )
-                })?;
-
+                let target_deadline = &target_deadlines[&deadline_idx];
                 let mut deadline =
                     deadlines.load_deadline(policy, store, deadline_idx).map_err(|e| {
                         e.downcast_default(
@@ -2640,6 +2663,14 @@ impl Actor {
                 ));
             }
 
+            fr_declaration_deadlines(
+                rt.policy(),
+                state,
+                to_process.deadlines(),
+                rt.curr_epoch(),
+                "recovery",
+            )?;
+
             let store = rt.store();
 
             let mut deadlines =
@@ -2648,33 +2679,8 @@ impl Actor {
             let sectors = Sectors::load(store, &state.sectors).map_err(|e| {
                 e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load sectors array")
             })?;
-            let curr_epoch = rt.curr_epoch();
             for (deadline_idx, partition_map) in to_process.iter() {
                 let policy = rt.policy();
-                let target_deadline = declaration_deadline_info(
-                    policy,
-                    state.current_proving_period_start(policy, curr_epoch),
-                    deadline_idx,
-                    curr_epoch,
-                )
-                .map_err(|e| {
-                    actor_error!(
-                        illegal_argument,
-                        "invalid recovery declaration deadline {}: {}",
-                        deadline_idx,
-                        e
-                    )
-                })?;
-
-                validate_fr_declaration_deadline(&target_deadline).map_err(|e| {
-                    actor_error!(
-                        illegal_argument,
-                        "failed recovery declaration at deadline {}: {}",
-                        deadline_idx,
-                        e
-                    )
-                })?;
-
                 let mut deadline =
                     deadlines.load_deadline(policy, store, deadline_idx).map_err(|e| {
                         e.downcast_default(
@@ -2732,10 +2738,10 @@ impl Actor {
             }
         }
 
-        let partitions = params.partitions.validate().map_err(|e| {
-            actor_error!(illegal_argument, "failed to parse partitions bitfield: {}", e)
-        })?;
-        let partition_count = partitions.len();
+        let partitions = &params.partitions;
+        let max_partitions = rt.policy().max_partitions_per_deadline;
+        let partition_count =
+            validate_bitfield(rt, partitions, max_partitions, max_partitions - 1, "partitions")?;
 
         let params_deadline = params.deadline;
 
@@ -2862,22 +2868,12 @@ impl Actor {
         rt: &mut impl Runtime,
         params: CompactSectorNumbersParams,
     ) -> Result<(), ActorError> {
-        let mask_sector_numbers = params
-            .mask_sector_numbers
-            .validate()
-            .map_err(|e| actor_error!(illegal_argument, "invalid mask bitfield: {}", e))?;
-
-        let last_sector_number = mask_sector_numbers
-            .last()
-            .ok_or_else(|| actor_error!(illegal_argument, "invalid mask bitfield"))?
-            as SectorNumber;
-
-        if last_sector_number > MAX_SECTOR_NUMBER {
-            return Err(actor_error!(
-                illegal_argument,
-                "masked sector number {} exceeded max sector number",
-                last_sector_number
-            ));
+        let mask_sector_numbers = &params.mask_sector_numbers;
+        let max_runs = rt.policy().addressed_sectors_max;
+        let mask_count =
+            validate_bitfield(rt, mask_sector_numbers, max_runs, MAX_SECTOR_NUMBER, "mask")?;
+        if mask_count == 0 {
+            return Err(actor_error!(illegal_argument, "invalid mask bitfield"));
         }
 
         rt.transaction(|state: &mut State, rt| {
@@ -2978,7 +2974,7 @@ impl Actor {
     fn report_consensus_fault(
         rt: &mut impl Runtime,
         params: ReportConsensusFaultParams,
-    ) -> Result<(), ActorError> {
+    ) -> Result<ReportConsensusFaultReturn, ActorError> {
         // Note: only the first report of any fault is processed because it sets the
         // ConsensusFaultElapsed state variable to an epoch after the fault, and reports prior to
         // that epoch are no longer valid
@@ -3021,61 +3017,72 @@ impl Actor {
 
         let mut pledge_delta = TokenAmount::zero();
 
-        let (burn_amount, reward_amount) = rt.transaction(|st: &mut State, rt| {
-            let mut info = get_miner_info(rt.store(), st)?;
-
-            // Verify miner hasn't already been faulted
-            if fault.epoch < info.consensus_fault_elapsed {
-                return Err(actor_error!(
-                    forbidden,
-                    "fault epoch {} is too old, last exclusion period ended at {}",
-                    fault.epoch,
-                    info.consensus_fault_elapsed
-                ));
-            }
+        let (burn_amount, reward_amount, ineligible_until) =
+            rt.transaction(|st: &mut State, rt| {
+                let mut info = get_miner_info(rt.store(), st)?;
 
-            st.apply_penalty(&fault_penalty).map_err(|e| {
-                actor_error!(illegal_state, format!("failed to apply penalty: {}", e))
-            })?;
+                // Verify miner hasn't already been faulted
+                if fault.epoch < info.consensus_fault_elapsed {
+                    return Err(actor_error!(
+                        forbidden,
+                        "fault epoch {} is too old, last exclusion period ended at {}",
+                        fault.epoch,
+                        info.consensus_fault_elapsed
+                    ));
+                }
 
-            // Pay penalty
-            let (penalty_from_vesting, penalty_from_balance) = st
-                .repay_partial_debt_in_priority_order(
-                    rt.store(),
-                    rt.curr_epoch(),
-                    &rt.current_balance(),
-                )
-                .map_err(|e| {
-                    e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to pay fees")
+                st.apply_penalty(&fault_penalty).map_err(|e| {
+                    actor_error!(illegal_state, format!("failed to apply penalty: {}", e))
                 })?;
 
-            let mut burn_amount = &penalty_from_vesting + &penalty_from_balance;
-            pledge_delta -= penalty_from_vesting;
+                // Pay penalty
+                let (penalty_from_vesting, penalty_from_balance) = st
+                    .repay_partial_debt_in_priority_order(
+                        rt.store(),
+                        rt.curr_epoch(),
+                        &rt.current_balance(),
+                    )
+                    .map_err(|e| {
+                        e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to pay fees")
+                    })?;
 
-            // clamp reward at funds burnt
-            let reward_amount = std::cmp::min(&burn_amount, &slasher_reward).clone();
-            burn_amount -= &reward_amount;
+                let mut burn_amount = &penalty_from_vesting + &penalty_from_balance;
+                pledge_delta -= penalty_from_vesting;
 
-            info.consensus_fault_elapsed =
-                rt.curr_epoch() + rt.policy().consensus_fault_ineligibility_duration;
+                // clamp reward at funds burnt
+                let reward_amount = std::cmp::min(&burn_amount, &slasher_reward).clone();
+                burn_amount -= &reward_amount;
 
-            st.save_info(rt.store(), &info).map_err(|e| {
-                e.downcast_default(ExitCode::USR_SERIALIZATION, "failed to save miner info")
-            })?;
+                info.consensus_fault_elapsed =
+                    rt.curr_epoch() + rt.policy().consensus_fault_ineligibility_duration;
 
-            Ok((burn_amount, reward_amount))
-        })?;
+                st.save_info(rt.store(), &info).map_err(|e| {
+                    e.downcast_default(ExitCode::USR_SERIALIZATION, "failed to save miner info")
+                })?;
 
-        if let Err(e) = rt.send(&reporter, METHOD_SEND, RawBytes::default(), reward_amount) {
-            error!("failed to send reward: {}", e);
-        }
+                Ok((burn_amount, reward_amount, info.consensus_fault_elapsed))
+            })?;
+
+        let reward =
+            match rt.send(&reporter, METHOD_SEND, RawBytes::default(), reward_amount.clone()) {
+                Ok(_) => reward_amount,
+                Err(e) => {
+                    error!("failed to send reward: {}", e);
+                    TokenAmount::zero()
+                }
+            };
 
         burn_funds(rt, burn_amount)?;
         notify_pledge_changed(rt, &pledge_delta)?;
 
         let state: State = rt.state()?;
         state.check_balance_invariants(&rt.current_balance()).map_err(balance_invariants_broken)?;
-        Ok(())
+        Ok(ReportConsensusFaultReturn {
+            fault_epoch: fault.epoch,
+            penalty: fault_penalty,
+            reward,
+            ineligible_until,
+        })
     }
 
     fn withdraw_balance(
@@ -3323,6 +3330,215 @@ impl Actor {
         })
     }
 
+    /// Computes the penalty that terminating the given sectors at the current epoch would incur,
+    /// without terminating them.
+    fn get_termination_fee(
+        rt: &mut impl Runtime,
+        params: GetTerminationFeeParams,
+    ) -> Result<GetTerminationFeeReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let sector_count = params.sectors.len();
+        let policy = rt.policy();
+        if sector_count > policy.addressed_sectors_max {
+            return Err(actor_error!(
+                illegal_argument,
+                "too many sectors for termination fee estimate: {} > {}",
+                sector_count,
+                policy.addressed_sectors_max
+            ));
+        }
+
+        let state: State = rt.state()?;
+        let info = get_miner_info(rt.store(), &state)?;
+        let sectors = Sectors::load(rt.store(), &state.sectors).map_err(|e| {
+            e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load sectors array")
+        })?;
+        let sector_infos = sectors.load_sector(&params.sectors)?;
+
+        let reward_stats = request_current_epoch_block_reward(rt)?;
+        let power_total = request_current_total_power(rt)?;
+        let fee = termination_penalty(
+            info.sector_size,
+            rt.curr_epoch(),
+            &reward_stats.this_epoch_reward_smoothed,
+            &power_total.quality_adj_power_smoothed,
+            &sector_infos,
+        );
+        Ok(GetTerminationFeeReturn { fee })
+    }
+
+    /// Returns the on-chain information of a single sector.
+    fn get_sector_info(
+        rt: &mut impl Runtime,
+        params: GetSectorInfoParams,
+    ) -> Result<GetSectorInfoReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let state: State = rt.state()?;
+        let sector = state
+            .get_sector(rt.store(), params.sector_number)
+            .map_err(|e| {
+                e.downcast_default(
+                    ExitCode::USR_ILLEGAL_STATE,
+                    format!("failed to load sector {}", params.sector_number),
+                )
+            })?
+            .ok_or_else(|| actor_error!(not_found; "sector not found: {}", params.sector_number))?;
+        Ok(GetSectorInfoReturn {
+            activation: sector.activation,
+            expiration: sector.expiration,
+            deal_weight: sector.deal_weight,
+            verified_deal_weight: sector.verified_deal_weight,
+            sealed_cid: sector.sealed_cid,
+            initial_pledge: sector.initial_pledge,
+        })
+    }
+
+    /// Returns the miner configuration which constrains new sectors and the deals in them.
+    fn get_onboarding_info(rt: &mut impl Runtime) -> Result<GetOnboardingInfoReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let state: State = rt.state()?;
+        let info = get_miner_info(rt.store(), &state)?;
+        let policy = rt.policy();
+
+        let mut seal_proof_types: Vec<RegisteredSealProof> = policy
+            .valid_pre_commit_proof_type
+            .iter()
+            .filter(|proof| proof.sector_size().map_or(false, |size| size == info.sector_size))
+            .cloned()
+            .collect();
+        // The policy holds a hash set, so order the result for a deterministic return value.
+        seal_proof_types.sort_by_key(|proof| i64::from(*proof));
+
+        Ok(GetOnboardingInfoReturn {
+            seal_proof_types,
+            window_post_proof_type: info.window_post_proof_type,
+            sector_size: info.sector_size,
+            window_post_partition_sectors: info.window_post_partition_sectors,
+            proving_period_start: state.current_proving_period_start(policy, rt.curr_epoch()),
+        })
+    }
+
+    /// Returns the deposit currently required to pre-commit one sector.
+    /// The deposit doesn't depend on the sector's deals.
+    fn estimate_pre_commit_deposit(rt: &mut impl Runtime) -> Result<TokenAmount, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let state: State = rt.state()?;
+        let info = get_miner_info(rt.store(), &state)?;
+        let reward_stats = request_current_epoch_block_reward(rt)?;
+        let power_total = request_current_total_power(rt)?;
+        Ok(pre_commit_deposit_for_power(
+            &reward_stats.this_epoch_reward_smoothed,
+            &power_total.quality_adj_power_smoothed,
+            &qa_power_max(info.sector_size),
+        ))
+    }
+
+    /// Returns the initial pledge that a sector with the given lifetime and deal weights
+    /// would require if activated now.
+    fn estimate_initial_pledge(
+        rt: &mut impl Runtime,
+        params: EstimateInitialPledgeParams,
+    ) -> Result<TokenAmount, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let state: State = rt.state()?;
+        let info = get_miner_info(rt.store(), &state)?;
+        if params.duration <= 0 {
+            return Err(actor_error!(
+                illegal_argument,
+                "sector duration {} must be positive",
+                params.duration
+            ));
+        }
+        if params.deal_weight.is_negative() || params.verified_deal_weight.is_negative() {
+            return Err(actor_error!(illegal_argument, "deal weights must not be negative"));
+        }
+        let max_weight = BigInt::from(info.sector_size as u64) * params.duration;
+        if &params.deal_weight + &params.verified_deal_weight > max_weight {
+            return Err(actor_error!(
+                illegal_argument,
+                "deal weights exceed sector size {} over duration {}",
+                info.sector_size as u64,
+                params.duration
+            ));
+        }
+
+        let reward_stats = request_current_epoch_block_reward(rt)?;
+        let power_total = request_current_total_power(rt)?;
+        let power = qa_power_for_weight(
+            info.sector_size,
+            params.duration,
+            &params.deal_weight,
+            &params.verified_deal_weight,
+        );
+        Ok(initial_pledge_for_power(
+            &power,
+            &reward_stats.this_epoch_baseline_power,
+            &reward_stats.this_epoch_reward_smoothed,
+            &power_total.quality_adj_power_smoothed,
+            &rt.total_fil_circ_supply(),
+        ))
+    }
+
+    /// Returns the miner's current proving deadline.
+    fn get_deadline_info(rt: &mut impl Runtime) -> Result<GetDeadlineInfoReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let state: State = rt.state()?;
+        let dl_info = state.deadline_info(rt.policy(), rt.curr_epoch());
+        Ok(GetDeadlineInfoReturn {
+            period_start: dl_info.period_start,
+            index: dl_info.index,
+            open: dl_info.open,
+            close: dl_info.close,
+            challenge: dl_info.challenge,
+            fault_cutoff: dl_info.fault_cutoff,
+        })
+    }
+
+    /// Returns the number of partitions in a deadline.
+    fn get_deadline_partition_count(
+        rt: &mut impl Runtime,
+        params: GetDeadlinePartitionCountParams,
+    ) -> Result<u64, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let state: State = rt.state()?;
+        let deadlines = state.load_deadlines(rt.store())?;
+        let deadline =
+            deadlines.load_deadline(rt.policy(), rt.store(), params.deadline).map_err(|e| {
+                e.downcast_default(
+                    ExitCode::USR_ILLEGAL_STATE,
+                    format!("failed to load deadline {}", params.deadline),
+                )
+            })?;
+        let partitions = deadline.partitions_amt(rt.store()).map_err(|e| {
+            e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load partitions")
+        })?;
+        Ok(partitions.count())
+    }
+
+    /// Returns the live and faulty sectors of a partition.
+    fn get_partition_sectors(
+        rt: &mut impl Runtime,
+        params: GetPartitionSectorsParams,
+    ) -> Result<GetPartitionSectorsReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let state: State = rt.state()?;
+        let deadlines = state.load_deadlines(rt.store())?;
+        let deadline =
+            deadlines.load_deadline(rt.policy(), rt.store(), params.deadline).map_err(|e| {
+                e.downcast_default(
+                    ExitCode::USR_ILLEGAL_STATE,
+                    format!("failed to load deadline {}", params.deadline),
+                )
+            })?;
+        let partition = deadline.load_partition(rt.store(), params.partition).map_err(|e| {
+            e.downcast_default(
+                ExitCode::USR_ILLEGAL_STATE,
+                format!("failed to load partition {}", params.partition),
+            )
+        })?;
+        Ok(GetPartitionSectorsReturn { live: partition.live_sectors(), faulty: partition.faults })
+    }
+
     fn repay_debt(rt: &mut impl Runtime) -> Result<(), ActorError> {
         let (from_vesting, from_balance, state) = rt.transaction(|state: &mut State, rt| {
             let info = get_miner_info(rt.store(), state)?;
@@ -4428,6 +4644,45 @@ fn validate_fr_declaration_deadline(deadline: &DeadlineInfo) -> anyhow::Result<(
     }
 }
 
+/// Computes the target deadline of every deadline addressed by a batch of fault or recovery
+/// declarations, rejecting the whole batch before any deadline state is loaded if any of them
+/// is invalid or past its fault cutoff.
+fn fr_declaration_deadlines(
+    policy: &Policy,
+    state: &State,
+    deadline_indices: impl Iterator<Item = u64>,
+    curr_epoch: ChainEpoch,
+    kind: &str,
+) -> Result<BTreeMap<u64, DeadlineInfo>, ActorError> {
+    let period_start = state.current_proving_period_start(policy, curr_epoch);
+    deadline_indices
+        .map(|deadline_idx| {
+            let target_deadline =
+                declaration_deadline_info(policy, period_start, deadline_idx, curr_epoch).map_err(
+                    |e| {
+                        actor_error!(
+                            illegal_argument,
+                            "invalid {} declaration deadline {}: {}",
+                            kind,
+                            deadline_idx,
+                            e
+                        )
+                    },
+                )?;
+            validate_fr_declaration_deadline(&target_deadline).map_err(|e| {
+                actor_error!(
+                    illegal_argument,
+                    "failed {} declaration at deadline {}: {}",
+                    kind,
+                    deadline_idx,
+                    e
+                )
+            })?;
+            Ok((deadline_idx, target_deadline))
+        })
+        .collect()
+}
+
 /// Validates that a partition contains the given sectors.
 fn validate_partition_contains_sectors(
     partition: &Partition,
@@ -4925,6 +5180,10 @@ impl ActorCode for Actor {
                 Self::report_consensus_fault(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::default())
             }
+            Some(Method::ReportConsensusFaultExported) => {
+                let res = Self::report_consensus_fault(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             Some(Method::WithdrawBalance) => {
                 let res = Self::withdraw_balance(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::serialize(&res)?)
@@ -4993,6 +5252,43 @@ impl ActorCode for Actor {
                 Self::extend_sector_expiration2(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::default())
             }
+            Some(Method::GetTerminationFee) => {
+                let res = Self::get_termination_fee(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::GetSectorInfo) => {
+                let res = Self::get_sector_info(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::GetOnboardingInfo) => {
+                let res = Self::get_onboarding_info(rt)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::EstimatePreCommitDeposit) => {
+                let res = Self::estimate_pre_commit_deposit(rt)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::EstimateInitialPledge) => {
+                let res = Self::estimate_initial_pledge(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::GetDeadlineInfo) => {
+                let res = Self::get_deadline_info(rt)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::GetDeadlinePartitionCount) => {
+                let res =
+                    Self::get_deadline_partition_count(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::GetPartitionSectors) => {
+                let res = Self::get_partition_sectors(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::CancelChangeWorkerAddress) => {
+                Self::cancel_change_worker_address(rt)?;
+                Ok(RawBytes::default())
+            }
             None => Err(actor_error!(unhandled_message, "Invalid method")),
         }
     }
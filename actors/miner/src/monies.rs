@@ -296,6 +296,7 @@ lazy_static! {
     static ref ESTIMATED_SINGLE_PRE_COMMIT_GAS_USAGE: BigInt = BigInt::from(16433324);
 }
 
+/// The network fee for a ProveCommitAggregate of `aggregate_size` sectors at `base_fee`.
 pub fn aggregate_prove_commit_network_fee(
     aggregate_size: i64,
     base_fee: &TokenAmount,
@@ -303,6 +304,7 @@ pub fn aggregate_prove_commit_network_fee(
     aggregate_network_fee(aggregate_size, &ESTIMATED_SINGLE_PROVE_COMMIT_GAS_USAGE, base_fee)
 }
 
+/// The network fee for a PreCommitSectorBatch of `aggregate_size` sectors at `base_fee`.
 pub fn aggregate_pre_commit_network_fee(
     aggregate_size: i64,
     base_fee: &TokenAmount,
@@ -310,6 +312,9 @@ pub fn aggregate_pre_commit_network_fee(
     aggregate_network_fee(aggregate_size, &ESTIMATED_SINGLE_PRE_COMMIT_GAS_USAGE, base_fee)
 }
 
+/// The network fee for a batch or aggregate of `aggregate_size` operations, each estimated to use
+/// `gas_usage` gas if submitted on its own. The fee is a fixed fraction of the gas the operations
+/// would have cost individually, priced at the base fee but no less than the batch balancer.
 pub fn aggregate_network_fee(
     aggregate_size: i64,
     gas_usage: &BigInt,
@@ -5,7 +5,7 @@ use std::collections::BTreeSet;
 
 use anyhow::anyhow;
 use cid::Cid;
-use fil_actors_runtime::{actor_error, ActorDowncast, ActorError, Array};
+use fil_actors_runtime::{actor_error, batch_ops, ActorDowncast, ActorError, Array};
 use fvm_ipld_amt::Error as AmtError;
 use fvm_ipld_bitfield::BitField;
 use fvm_ipld_blockstore::Blockstore;
@@ -54,18 +54,13 @@ impl<'db, BS: Blockstore> Sectors<'db, BS> {
     }
 
     pub fn store(&mut self, infos: Vec<SectorOnChainInfo>) -> anyhow::Result<()> {
-        for info in infos {
-            let sector_number = info.sector_number;
-
-            if sector_number > MAX_SECTOR_NUMBER {
-                return Err(anyhow!("sector number {} out of range", info.sector_number));
-            }
-
-            self.amt.set(sector_number, info).map_err(|e| {
-                e.downcast_wrap(format!("failed to store sector {}", sector_number))
-            })?;
+        if let Some(info) = infos.iter().find(|info| info.sector_number > MAX_SECTOR_NUMBER) {
+            return Err(anyhow!("sector number {} out of range", info.sector_number));
         }
 
+        let entries = infos.into_iter().map(|info| (info.sector_number, info)).collect();
+        batch_ops::set_many(&mut self.amt, entries)
+            .map_err(|e| e.downcast_wrap("failed to store sectors"))?;
         Ok(())
     }
 
@@ -9,8 +9,8 @@ use cid::multihash::Code;
 use cid::Cid;
 use fil_actors_runtime::runtime::Policy;
 use fil_actors_runtime::{
-    actor_error, make_empty_map, make_map_with_root_and_bitwidth, u64_key, ActorDowncast,
-    ActorError, Array,
+    actor_error, batch_ops, make_empty_map, make_map_with_root_and_bitwidth, u64_key,
+    ActorDowncast, ActorError, Array,
 };
 use fvm_ipld_amt::Error as AmtError;
 use fvm_ipld_bitfield::BitField;
@@ -39,6 +39,7 @@ use super::{
 
 const PRECOMMIT_EXPIRY_AMT_BITWIDTH: u32 = 6;
 pub const SECTORS_AMT_BITWIDTH: u32 = 5;
+pub const PRECOMMIT_HAMT_BITWIDTH: u32 = HAMT_BIT_WIDTH;
 
 /// Balance of Miner Actor should be greater than or equal to
 /// the sum of PreCommitDeposits and LockedFunds.
@@ -127,7 +128,7 @@ impl State {
         deadline_idx: u64,
     ) -> anyhow::Result<Self> {
         let empty_precommit_map =
-            make_empty_map::<_, ()>(store, HAMT_BIT_WIDTH).flush().map_err(|e| {
+            make_empty_map::<_, ()>(store, PRECOMMIT_HAMT_BITWIDTH).flush().map_err(|e| {
                 e.downcast_default(
                     ExitCode::USR_ILLEGAL_STATE,
                     "failed to construct empty precommit map",
@@ -290,18 +291,16 @@ impl State {
         store: &BS,
         precommits: Vec<SectorPreCommitOnChainInfo>,
     ) -> anyhow::Result<()> {
-        let mut precommitted =
-            make_map_with_root_and_bitwidth(&self.pre_committed_sectors, store, HAMT_BIT_WIDTH)?;
-        for precommit in precommits.into_iter() {
-            let sector_no = precommit.info.sector_number;
-            let modified = precommitted
-                .set_if_absent(u64_key(precommit.info.sector_number), precommit)
-                .map_err(|e| {
-                    e.downcast_wrap(format!("failed to store precommitment for {:?}", sector_no,))
-                })?;
-            if !modified {
-                return Err(anyhow!("sector {} already pre-commited", sector_no));
-            }
+        let mut precommitted = make_map_with_root_and_bitwidth(
+            &self.pre_committed_sectors,
+            store,
+            PRECOMMIT_HAMT_BITWIDTH,
+        )?;
+        let entries = precommits.into_iter().map(|p| (p.info.sector_number, p));
+        if let Some(sector_no) = batch_ops::set_many_if_absent(&mut precommitted, entries)
+            .map_err(|e| e.downcast_wrap("failed to store precommitments"))?
+        {
+            return Err(anyhow!("sector {} already pre-commited", sector_no));
         }
 
         self.pre_committed_sectors = precommitted.flush()?;
@@ -313,8 +312,11 @@ impl State {
         store: &BS,
         sector_num: SectorNumber,
     ) -> Result<Option<SectorPreCommitOnChainInfo>, HamtError> {
-        let precommitted =
-            make_map_with_root_and_bitwidth(&self.pre_committed_sectors, store, HAMT_BIT_WIDTH)?;
+        let precommitted = make_map_with_root_and_bitwidth(
+            &self.pre_committed_sectors,
+            store,
+            PRECOMMIT_HAMT_BITWIDTH,
+        )?;
         Ok(precommitted.get(&u64_key(sector_num))?.cloned())
     }
 
@@ -327,22 +329,11 @@ impl State {
         let precommitted = make_map_with_root_and_bitwidth::<_, SectorPreCommitOnChainInfo>(
             &self.pre_committed_sectors,
             store,
-            HAMT_BIT_WIDTH,
+            PRECOMMIT_HAMT_BITWIDTH,
         )?;
-        let mut result = Vec::with_capacity(sector_numbers.len());
-
-        for &sector_number in sector_numbers {
-            let info = match precommitted.get(&u64_key(sector_number)).map_err(|e| {
-                e.downcast_wrap(format!("failed to load precommitment for {}", sector_number))
-            })? {
-                Some(info) => info.clone(),
-                None => continue,
-            };
-
-            result.push(info);
-        }
-
-        Ok(result)
+        let infos = batch_ops::get_many(&precommitted, sector_numbers)
+            .map_err(|e| e.downcast_wrap("failed to load precommitments"))?;
+        Ok(infos.into_iter().flatten().cloned().collect())
     }
 
     pub fn delete_precommitted_sectors<BS: Blockstore>(
@@ -353,14 +344,13 @@ impl State {
         let mut precommitted = make_map_with_root_and_bitwidth::<_, SectorPreCommitOnChainInfo>(
             &self.pre_committed_sectors,
             store,
-            HAMT_BIT_WIDTH,
+            PRECOMMIT_HAMT_BITWIDTH,
         )?;
 
-        for &sector_num in sector_nums {
-            let prev_entry = precommitted.delete(&u64_key(sector_num))?;
-            if prev_entry.is_none() {
-                return Err(format!("sector {} doesn't exist", sector_num).into());
-            }
+        if let Some(sector_num) =
+            batch_ops::delete_many(&mut precommitted, sector_nums.iter().copied())?
+        {
+            return Err(format!("sector {} doesn't exist", sector_num).into());
         }
 
         self.pre_committed_sectors = precommitted.flush()?;
@@ -1176,8 +1166,11 @@ impl State {
         sector_nos: &BitField,
     ) -> anyhow::Result<Vec<SectorPreCommitOnChainInfo>> {
         let mut precommits = Vec::new();
-        let precommitted =
-            make_map_with_root_and_bitwidth(&self.pre_committed_sectors, store, HAMT_BIT_WIDTH)?;
+        let precommitted = make_map_with_root_and_bitwidth(
+            &self.pre_committed_sectors,
+            store,
+            PRECOMMIT_HAMT_BITWIDTH,
+        )?;
         for sector_no in sector_nos.iter() {
             if sector_no as u64 > MAX_SECTOR_NUMBER {
                 return Err(
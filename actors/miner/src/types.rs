@@ -13,7 +13,7 @@ use fvm_shared::econ::TokenAmount;
 use fvm_shared::randomness::Randomness;
 use fvm_shared::sector::{
     PoStProof, RegisteredPoStProof, RegisteredSealProof, RegisteredUpdateProof, SectorNumber,
-    StoragePower,
+    SectorSize, StoragePower,
 };
 use fvm_shared::smooth::FilterEstimate;
 
@@ -269,6 +269,18 @@ pub struct ReportConsensusFaultParams {
     pub header_extra: Vec<u8>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct ReportConsensusFaultReturn {
+    /// The epoch of the reported fault.
+    pub fault_epoch: ChainEpoch,
+    /// The penalty charged to the miner, any of which not paid becomes fee debt.
+    pub penalty: TokenAmount,
+    /// The reward paid to the reporter.
+    pub reward: TokenAmount,
+    /// The epoch until which the miner is ineligible to participate in consensus.
+    pub ineligible_until: ChainEpoch,
+}
+
 #[derive(Clone, Serialize_tuple, Deserialize_tuple)]
 pub struct WithdrawBalanceParams {
     pub amount_requested: TokenAmount,
@@ -482,3 +494,115 @@ pub struct GetBeneficiaryReturn {
 }
 
 impl Cbor for GetBeneficiaryReturn {}
+
+#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct GetTerminationFeeParams {
+    pub sectors: BitField,
+}
+
+impl Cbor for GetTerminationFeeParams {}
+
+#[derive(Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct GetTerminationFeeReturn {
+    pub fee: TokenAmount,
+}
+
+impl Cbor for GetTerminationFeeReturn {}
+
+#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct GetSectorInfoParams {
+    pub sector_number: SectorNumber,
+}
+
+impl Cbor for GetSectorInfoParams {}
+
+#[derive(Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct GetSectorInfoReturn {
+    /// Epoch during which the sector proof was accepted
+    pub activation: ChainEpoch,
+    /// Epoch during which the sector expires
+    pub expiration: ChainEpoch,
+    /// Integral of active deals over sector lifetime
+    #[serde(with = "bigint_ser")]
+    pub deal_weight: DealWeight,
+    /// Integral of active verified deals over sector lifetime
+    #[serde(with = "bigint_ser")]
+    pub verified_deal_weight: DealWeight,
+    /// CommR
+    pub sealed_cid: Cid,
+    /// Pledge collected to commit this sector
+    pub initial_pledge: TokenAmount,
+}
+
+impl Cbor for GetSectorInfoReturn {}
+
+#[derive(Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct GetOnboardingInfoReturn {
+    /// Seal proof types with which the miner may pre-commit new sectors
+    pub seal_proof_types: Vec<RegisteredSealProof>,
+    pub window_post_proof_type: RegisteredPoStProof,
+    /// Size of the miner's sectors, which is also the largest (padded) piece size they can hold
+    pub sector_size: SectorSize,
+    pub window_post_partition_sectors: u64,
+    /// First epoch of the miner's current proving period
+    pub proving_period_start: ChainEpoch,
+}
+
+impl Cbor for GetOnboardingInfoReturn {}
+
+#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct EstimateInitialPledgeParams {
+    /// Lifetime of the hypothetical sector, from activation to expiration
+    pub duration: ChainEpoch,
+    /// Integral of active deals over the sector lifetime
+    #[serde(with = "bigint_ser")]
+    pub deal_weight: DealWeight,
+    /// Integral of active verified deals over the sector lifetime
+    #[serde(with = "bigint_ser")]
+    pub verified_deal_weight: DealWeight,
+}
+
+impl Cbor for EstimateInitialPledgeParams {}
+
+#[derive(Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct GetDeadlineInfoReturn {
+    /// First epoch of the proving period containing the deadline
+    pub period_start: ChainEpoch,
+    /// Index of the current deadline within the proving period
+    pub index: u64,
+    /// First epoch from which a proof may be submitted
+    pub open: ChainEpoch,
+    /// First epoch from which a proof may no longer be submitted
+    pub close: ChainEpoch,
+    /// Epoch at which the chain is sampled for the challenge
+    pub challenge: ChainEpoch,
+    /// First epoch at which a fault declaration for the deadline is rejected
+    pub fault_cutoff: ChainEpoch,
+}
+
+impl Cbor for GetDeadlineInfoReturn {}
+
+#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct GetDeadlinePartitionCountParams {
+    pub deadline: u64,
+}
+
+impl Cbor for GetDeadlinePartitionCountParams {}
+
+#[derive(Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct GetPartitionSectorsParams {
+    pub deadline: u64,
+    pub partition: u64,
+}
+
+impl Cbor for GetPartitionSectorsParams {}
+
+#[derive(Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct GetPartitionSectorsReturn {
+    /// Sectors in the partition which have not been terminated, including faulty ones
+    pub live: BitField,
+    /// Sectors in the partition which are currently faulty
+    pub faulty: BitField,
+}
+
+impl Cbor for GetPartitionSectorsReturn {}
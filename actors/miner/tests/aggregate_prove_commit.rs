@@ -2,15 +2,18 @@ use std::collections::HashMap;
 
 use fil_actor_market::DealSpaces;
 use fil_actor_miner::{
-    initial_pledge_for_power, qa_power_for_weight, PowerPair, QUALITY_BASE_MULTIPLIER,
-    VERIFIED_DEAL_WEIGHT_MULTIPLIER,
+    initial_pledge_for_power, qa_power_for_weight, Actor, Method, PowerPair,
+    QUALITY_BASE_MULTIPLIER, VERIFIED_DEAL_WEIGHT_MULTIPLIER,
 };
 use fil_actors_runtime::runtime::Runtime;
 use fvm_ipld_bitfield::BitField;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::error::ExitCode;
 use fvm_shared::{bigint::BigInt, clock::ChainEpoch, econ::TokenAmount};
 
 mod util;
-use fil_actors_runtime::test_utils::make_piece_cid;
+use fil_actors_runtime::test_utils::{expect_abort, make_piece_cid, ACCOUNT_ACTOR_CODE_ID};
 use num_traits::Zero;
 use util::*;
 
@@ -168,3 +171,31 @@ fn valid_precommits_then_aggregate_provecommit() {
     assert_eq!(ten_sectors_power, entry.active_power);
     assert_eq!(PowerPair::zero(), entry.faulty_power);
 }
+
+#[test]
+fn unauthorized_caller_rejected_before_bitfield_validation() {
+    let period_offset = ChainEpoch::from(100);
+
+    let actor = ActorHarness::new(period_offset);
+    let mut rt = actor.new_runtime();
+    rt.set_epoch(period_offset + 1);
+    actor.construct_and_verify(&mut rt);
+
+    // Every other sector, so each sector is a separate run.
+    let mut sector_nos_bf = BitField::new();
+    for i in 0..10u64 {
+        sector_nos_bf.set(2 * i);
+    }
+
+    // No gas charge is expected, so the mock runtime fails on any charge.
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(1234));
+    rt.expect_validate_caller_addr(actor.caller_addrs());
+    expect_abort(
+        ExitCode::USR_FORBIDDEN,
+        rt.call::<Actor>(
+            Method::ProveCommitAggregate as u64,
+            &RawBytes::serialize(make_prove_commit_aggregate(&sector_nos_bf)).unwrap(),
+        ),
+    );
+    rt.verify();
+}
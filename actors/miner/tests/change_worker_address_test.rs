@@ -107,6 +107,52 @@ fn change_cannot_be_overridden() {
     h.check_state(&rt);
 }
 
+#[test]
+fn pending_change_can_be_cancelled() {
+    let (h, mut rt) = setup();
+
+    let original_control_addresses = h.control_addrs.clone();
+    let (new_worker_1, new_worker_2) = (Address::new_id(999), Address::new_id(1023));
+
+    let current_epoch = 2970;
+    rt.set_epoch(current_epoch);
+    h.change_worker_address(&mut rt, new_worker_1, original_control_addresses.clone()).unwrap();
+
+    // cancel the pending change
+    h.cancel_change_worker_address(&mut rt).unwrap();
+    assert!(h.get_info(&rt).pending_worker_key.is_none());
+
+    // nothing is enacted once the original effective epoch passes
+    rt.set_epoch(current_epoch + rt.policy().worker_key_change_delay);
+    h.confirm_update_worker_key(&mut rt).unwrap();
+    assert_eq!(h.worker, h.get_info(&rt).worker);
+
+    // a new change can be scheduled after cancelling
+    let current_epoch = rt.epoch;
+    h.change_worker_address(&mut rt, new_worker_2, original_control_addresses).unwrap();
+    let pending_worker_key = h.get_info(&rt).pending_worker_key.unwrap();
+    assert_eq!(new_worker_2, pending_worker_key.new_worker);
+    assert_eq!(
+        current_epoch + rt.policy().worker_key_change_delay,
+        pending_worker_key.effective_at
+    );
+
+    h.check_state(&rt);
+}
+
+#[test]
+fn cancel_fails_without_pending_change() {
+    let (h, mut rt) = setup();
+
+    expect_abort_contains_message(
+        ExitCode::USR_ILLEGAL_ARGUMENT,
+        "no pending worker key change",
+        h.cancel_change_worker_address(&mut rt),
+    );
+    rt.reset();
+    h.check_state(&rt);
+}
+
 #[test]
 fn successfully_resolve_and_change_only_control_addresses() {
     let (h, mut rt) = setup();
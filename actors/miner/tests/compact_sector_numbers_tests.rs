@@ -150,4 +150,17 @@ mod compact_sector_numbers_test {
         rt.reset();
         check_state_invariants_from_mock_runtime(&rt);
     }
+
+    #[test]
+    fn mask_with_too_many_runs_aborts() {
+        let (h, mut rt) = setup();
+        let max_runs = rt.policy.addressed_sectors_max;
+        let alternating: Vec<u64> = (0..=max_runs).map(|i| 2 * i).collect();
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            h.compact_sector_numbers_raw(&mut rt, h.worker, bitfield_from_slice(&alternating)),
+        );
+        rt.reset();
+        check_state_invariants_from_mock_runtime(&rt);
+    }
 }
@@ -0,0 +1,123 @@
+use fil_actor_miner::{
+    Actor, GetDeadlineInfoReturn, GetDeadlinePartitionCountParams, GetPartitionSectorsParams,
+    GetPartitionSectorsReturn, Method,
+};
+use fil_actors_runtime::test_utils::{expect_abort, MockRuntime};
+use fvm_ipld_bitfield::BitField;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::clock::ChainEpoch;
+use fvm_shared::error::ExitCode;
+
+mod util;
+use util::*;
+
+const PERIOD_OFFSET: ChainEpoch = 100;
+const DEFAULT_SECTOR_EXPIRATION: u64 = 220;
+
+fn get_deadline_info(rt: &mut MockRuntime) -> GetDeadlineInfoReturn {
+    rt.expect_validate_caller_any();
+    let ret = rt.call::<Actor>(Method::GetDeadlineInfo as u64, &RawBytes::default()).unwrap();
+    rt.verify();
+    ret.deserialize().unwrap()
+}
+
+fn get_deadline_partition_count(rt: &mut MockRuntime, deadline: u64) -> u64 {
+    rt.expect_validate_caller_any();
+    let params = GetDeadlinePartitionCountParams { deadline };
+    let ret = rt
+        .call::<Actor>(
+            Method::GetDeadlinePartitionCount as u64,
+            &RawBytes::serialize(params).unwrap(),
+        )
+        .unwrap();
+    rt.verify();
+    ret.deserialize().unwrap()
+}
+
+fn get_partition_sectors(
+    rt: &mut MockRuntime,
+    deadline: u64,
+    partition: u64,
+) -> GetPartitionSectorsReturn {
+    rt.expect_validate_caller_any();
+    let params = GetPartitionSectorsParams { deadline, partition };
+    let ret = rt
+        .call::<Actor>(Method::GetPartitionSectors as u64, &RawBytes::serialize(params).unwrap())
+        .unwrap();
+    rt.verify();
+    ret.deserialize().unwrap()
+}
+
+#[test]
+fn get_deadline_info_returns_current_deadline() {
+    let h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    h.construct_and_verify(&mut rt);
+
+    let expected = h.current_deadline(&rt);
+    let ret = get_deadline_info(&mut rt);
+    assert_eq!(expected.period_start, ret.period_start);
+    assert_eq!(expected.index, ret.index);
+    assert_eq!(expected.open, ret.open);
+    assert_eq!(expected.close, ret.close);
+    assert_eq!(expected.challenge, ret.challenge);
+    assert_eq!(expected.fault_cutoff, ret.fault_cutoff);
+    h.check_state(&rt);
+}
+
+#[test]
+fn partition_queries_report_live_and_faulty_sectors() {
+    let h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    rt.set_balance(BIG_BALANCE.clone());
+    h.construct_and_verify(&mut rt);
+
+    let sectors = h.commit_and_prove_sectors(&mut rt, 2, DEFAULT_SECTOR_EXPIRATION, vec![], true);
+    let st = h.get_state(&rt);
+    let (dl_idx, p_idx) = st.find_sector(&rt.policy, &rt.store, sectors[0].sector_number).unwrap();
+    assert_eq!(1, get_deadline_partition_count(&mut rt, dl_idx));
+    assert_eq!(
+        0,
+        get_deadline_partition_count(&mut rt, (dl_idx + 1) % rt.policy.wpost_period_deadlines)
+    );
+
+    let all_sectors = bitfield_from_slice(&[sectors[0].sector_number, sectors[1].sector_number]);
+    let ret = get_partition_sectors(&mut rt, dl_idx, p_idx);
+    assert_eq!(all_sectors, ret.live);
+    assert_eq!(BitField::new(), ret.faulty);
+
+    h.advance_and_submit_posts(&mut rt, &sectors);
+    h.declare_faults(&mut rt, &sectors[0..1]);
+    let ret = get_partition_sectors(&mut rt, dl_idx, p_idx);
+    assert_eq!(all_sectors, ret.live);
+    assert_eq!(bitfield_from_slice(&[sectors[0].sector_number]), ret.faulty);
+    h.check_state(&rt);
+}
+
+#[test]
+fn partition_queries_reject_missing_deadlines_and_partitions() {
+    let h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    h.construct_and_verify(&mut rt);
+
+    let deadlines = rt.policy.wpost_period_deadlines;
+    rt.expect_validate_caller_any();
+    let params = GetDeadlinePartitionCountParams { deadline: deadlines };
+    expect_abort(
+        ExitCode::USR_ILLEGAL_ARGUMENT,
+        rt.call::<Actor>(
+            Method::GetDeadlinePartitionCount as u64,
+            &RawBytes::serialize(params).unwrap(),
+        ),
+    );
+    rt.verify();
+
+    rt.expect_validate_caller_any();
+    let params = GetPartitionSectorsParams { deadline: 0, partition: 0 };
+    expect_abort(
+        ExitCode::USR_NOT_FOUND,
+        rt.call::<Actor>(Method::GetPartitionSectors as u64, &RawBytes::serialize(params).unwrap()),
+    );
+    rt.verify();
+    h.check_state(&rt);
+}
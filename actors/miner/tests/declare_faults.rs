@@ -1,8 +1,13 @@
 use fil_actor_miner::pledge_penalty_for_continued_fault;
 use fil_actor_miner::power_for_sectors;
+use fil_actor_miner::{Actor, DeclareFaultsParams, FaultDeclaration, Method};
+use fil_actors_runtime::test_utils::{expect_abort_contains_message, ACCOUNT_ACTOR_CODE_ID};
+use fvm_ipld_bitfield::BitField;
+use fvm_ipld_encoding::RawBytes;
 
 use fvm_shared::clock::ChainEpoch;
 use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
 
 use num_traits::Zero;
 
@@ -64,3 +69,44 @@ fn declare_fault_pays_fee_at_window_post() {
     );
     h.check_state(&rt);
 }
+
+#[test]
+fn late_declaration_rejects_whole_batch() {
+    let mut h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    rt.set_balance(BIG_BALANCE.clone());
+    h.construct_and_verify(&mut rt);
+    let all_sectors =
+        h.commit_and_prove_sectors(&mut rt, 1, DEFAULT_SECTOR_EXPIRATION as u64, vec![], true);
+    h.advance_and_submit_posts(&mut rt, &all_sectors);
+
+    let st = h.get_state(&rt);
+    let sector_number = all_sectors[0].sector_number;
+    let (dl_idx, p_idx) = st.find_sector(&rt.policy, &rt.store, sector_number).unwrap();
+
+    // the current deadline is past its fault cutoff, so declaring in it invalidates the batch
+    let current = h.deadline(&rt);
+    let params = DeclareFaultsParams {
+        faults: vec![
+            FaultDeclaration {
+                deadline: dl_idx,
+                partition: p_idx,
+                sectors: BitField::try_from_bits([sector_number].iter().copied()).unwrap(),
+            },
+            FaultDeclaration { deadline: current.index, partition: 0, sectors: BitField::new() },
+        ],
+    };
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, h.worker);
+    rt.expect_validate_caller_addr(h.caller_addrs());
+    expect_abort_contains_message(
+        ExitCode::USR_ILLEGAL_ARGUMENT,
+        "late fault or recovery declaration",
+        rt.call::<Actor>(Method::DeclareFaults as u64, &RawBytes::serialize(params).unwrap()),
+    );
+    rt.reset();
+
+    // no faults were recorded for the valid declaration either
+    let dl = h.get_deadline(&rt, dl_idx);
+    assert!(dl.faulty_power.is_zero());
+    h.check_state(&rt);
+}
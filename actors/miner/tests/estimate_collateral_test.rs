@@ -0,0 +1,122 @@
+use fil_actor_miner::{
+    initial_pledge_for_power, pre_commit_deposit_for_power, qa_power_for_weight, qa_power_max,
+    Actor, EstimateInitialPledgeParams, Method,
+};
+use fil_actors_runtime::runtime::Runtime;
+use fil_actors_runtime::test_utils::{expect_abort, MockRuntime};
+use fil_actors_runtime::DealWeight;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::clock::ChainEpoch;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use num_traits::Zero;
+
+mod util;
+use util::*;
+
+const PERIOD_OFFSET: ChainEpoch = 100;
+
+fn setup() -> (ActorHarness, MockRuntime) {
+    let h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    h.construct_and_verify(&mut rt);
+    (h, rt)
+}
+
+fn estimate_initial_pledge(
+    h: &ActorHarness,
+    rt: &mut MockRuntime,
+    params: EstimateInitialPledgeParams,
+) -> TokenAmount {
+    rt.expect_validate_caller_any();
+    h.expect_query_network_info(rt);
+    let ret = rt
+        .call::<Actor>(Method::EstimateInitialPledge as u64, &RawBytes::serialize(params).unwrap())
+        .unwrap();
+    rt.verify();
+    ret.deserialize().unwrap()
+}
+
+#[test]
+fn estimates_pre_commit_deposit() {
+    let (h, mut rt) = setup();
+
+    rt.expect_validate_caller_any();
+    h.expect_query_network_info(&mut rt);
+    let ret: TokenAmount = rt
+        .call::<Actor>(Method::EstimatePreCommitDeposit as u64, &RawBytes::default())
+        .unwrap()
+        .deserialize()
+        .unwrap();
+    rt.verify();
+
+    let expected = pre_commit_deposit_for_power(
+        &h.epoch_reward_smooth,
+        &h.epoch_qa_power_smooth,
+        &qa_power_max(h.sector_size),
+    );
+    assert_eq!(expected, ret);
+    h.check_state(&rt);
+}
+
+#[test]
+fn estimates_initial_pledge_for_deal_weights() {
+    let (h, mut rt) = setup();
+    let duration = 180 * rt.policy.wpost_proving_period;
+    let full_weight = DealWeight::from(h.sector_size as u64) * duration;
+
+    let mut pledges = Vec::new();
+    for verified_deal_weight in [DealWeight::zero(), full_weight] {
+        let params = EstimateInitialPledgeParams {
+            duration,
+            deal_weight: DealWeight::zero(),
+            verified_deal_weight: verified_deal_weight.clone(),
+        };
+        let ret = estimate_initial_pledge(&h, &mut rt, params);
+
+        let power = qa_power_for_weight(
+            h.sector_size,
+            duration,
+            &DealWeight::zero(),
+            &verified_deal_weight,
+        );
+        let expected = initial_pledge_for_power(
+            &power,
+            &h.baseline_power,
+            &h.epoch_reward_smooth,
+            &h.epoch_qa_power_smooth,
+            &rt.total_fil_circ_supply(),
+        );
+        assert_eq!(expected, ret);
+        pledges.push(ret);
+    }
+    // Verified deals raise the sector's power, and so its pledge.
+    assert!(pledges[1] > pledges[0]);
+    h.check_state(&rt);
+}
+
+#[test]
+fn rejects_invalid_estimate_params() {
+    let (h, mut rt) = setup();
+    let duration = 180 * rt.policy.wpost_proving_period;
+    let full_weight = DealWeight::from(h.sector_size as u64) * duration;
+
+    let invalid = [
+        (0, DealWeight::zero(), DealWeight::zero()),
+        (duration, DealWeight::from(-1), DealWeight::zero()),
+        (duration, full_weight, DealWeight::from(1)),
+    ];
+    for (duration, deal_weight, verified_deal_weight) in invalid {
+        rt.expect_validate_caller_any();
+        let params = EstimateInitialPledgeParams { duration, deal_weight, verified_deal_weight };
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            rt.call::<Actor>(
+                Method::EstimateInitialPledge as u64,
+                &RawBytes::serialize(params).unwrap(),
+            ),
+        );
+        rt.reset();
+    }
+    h.check_state(&rt);
+}
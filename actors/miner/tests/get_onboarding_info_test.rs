@@ -0,0 +1,44 @@
+use fil_actor_miner::{Actor, GetOnboardingInfoReturn, Method};
+use fil_actors_runtime::test_utils::MockRuntime;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::clock::ChainEpoch;
+
+mod util;
+use util::*;
+
+const PERIOD_OFFSET: ChainEpoch = 100;
+
+fn get_onboarding_info(rt: &mut MockRuntime) -> GetOnboardingInfoReturn {
+    rt.expect_validate_caller_any();
+    let ret = rt.call::<Actor>(Method::GetOnboardingInfo as u64, &RawBytes::default()).unwrap();
+    rt.verify();
+    ret.deserialize().unwrap()
+}
+
+#[test]
+fn get_onboarding_info_returns_miner_configuration() {
+    let h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    h.construct_and_verify(&mut rt);
+
+    let ret = get_onboarding_info(&mut rt);
+    assert!(ret.seal_proof_types.contains(&h.seal_proof_type));
+    for proof in &ret.seal_proof_types {
+        assert_eq!(h.sector_size, proof.sector_size().unwrap());
+    }
+    assert_eq!(h.window_post_proof_type, ret.window_post_proof_type);
+    assert_eq!(h.sector_size, ret.sector_size);
+    assert_eq!(h.partition_size, ret.window_post_partition_sectors);
+
+    let st = h.get_state(&rt);
+    assert_eq!(st.current_proving_period_start(&rt.policy, rt.epoch), ret.proving_period_start);
+
+    // The proving period start follows the current epoch.
+    rt.set_epoch(rt.epoch + rt.policy.wpost_proving_period);
+    let next = get_onboarding_info(&mut rt);
+    assert_eq!(
+        ret.proving_period_start + rt.policy.wpost_proving_period,
+        next.proving_period_start
+    );
+    h.check_state(&rt);
+}
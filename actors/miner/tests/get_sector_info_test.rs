@@ -0,0 +1,61 @@
+use fil_actor_miner::{Actor, GetSectorInfoParams, GetSectorInfoReturn, Method};
+use fil_actors_runtime::test_utils::{expect_abort_contains_message, MockRuntime};
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::clock::ChainEpoch;
+use fvm_shared::error::ExitCode;
+
+mod util;
+use util::*;
+
+const PERIOD_OFFSET: ChainEpoch = 100;
+
+fn setup() -> (ActorHarness, MockRuntime) {
+    let h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    h.construct_and_verify(&mut rt);
+    rt.balance.replace(BIG_BALANCE.clone());
+
+    (h, rt)
+}
+
+#[test]
+fn get_sector_info_returns_on_chain_info() {
+    let (mut h, mut rt) = setup();
+    rt.set_epoch(200);
+    let sectors_info =
+        h.commit_and_prove_sectors(&mut rt, 1, DEFAULT_SECTOR_EXPIRATION, vec![], true);
+    let sector = &sectors_info[0];
+
+    rt.expect_validate_caller_any();
+    let params = GetSectorInfoParams { sector_number: sector.sector_number };
+    let ret = rt
+        .call::<Actor>(Method::GetSectorInfo as u64, &RawBytes::serialize(params).unwrap())
+        .unwrap();
+    rt.verify();
+
+    let expected = GetSectorInfoReturn {
+        activation: sector.activation,
+        expiration: sector.expiration,
+        deal_weight: sector.deal_weight.clone(),
+        verified_deal_weight: sector.verified_deal_weight.clone(),
+        sealed_cid: sector.sealed_cid,
+        initial_pledge: sector.initial_pledge.clone(),
+    };
+    assert_eq!(expected, ret.deserialize::<GetSectorInfoReturn>().unwrap());
+    h.check_state(&rt);
+}
+
+#[test]
+fn get_sector_info_fails_for_unknown_sector() {
+    let (h, mut rt) = setup();
+
+    rt.expect_validate_caller_any();
+    let params = GetSectorInfoParams { sector_number: 100 };
+    expect_abort_contains_message(
+        ExitCode::USR_NOT_FOUND,
+        "sector not found",
+        rt.call::<Actor>(Method::GetSectorInfo as u64, &RawBytes::serialize(params).unwrap()),
+    );
+    rt.verify();
+    h.check_state(&rt);
+}
@@ -0,0 +1,86 @@
+use fil_actor_miner::{
+    expected_reward_for_power, pledge_penalty_for_termination, qa_power_for_sector, Actor,
+    GetTerminationFeeParams, Method, State, INITIAL_PLEDGE_PROJECTION_PERIOD,
+};
+use fil_actors_runtime::runtime::Runtime;
+use fil_actors_runtime::test_utils::{expect_abort_contains_message, MockRuntime};
+use fil_actors_runtime::EPOCHS_IN_DAY;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::bigint::Zero;
+use fvm_shared::clock::ChainEpoch;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+
+mod util;
+use util::*;
+
+const PERIOD_OFFSET: ChainEpoch = 100;
+
+fn setup() -> (ActorHarness, MockRuntime) {
+    let h = ActorHarness::new(PERIOD_OFFSET);
+    let mut rt = h.new_runtime();
+    h.construct_and_verify(&mut rt);
+    rt.balance.replace(BIG_BALANCE.clone());
+
+    (h, rt)
+}
+
+#[test]
+fn termination_fee_matches_penalty_without_terminating() {
+    let (mut h, mut rt) = setup();
+    rt.set_epoch(200);
+
+    let sectors_info =
+        h.commit_and_prove_sectors(&mut rt, 1, DEFAULT_SECTOR_EXPIRATION, vec![], true);
+    h.advance_and_submit_posts(&mut rt, &sectors_info);
+    rt.set_epoch(rt.epoch + 100);
+
+    let sector = &sectors_info[0];
+    let sector_size = sector.seal_proof.sector_size().unwrap();
+    let sector_power = qa_power_for_sector(sector_size, sector);
+    let day_reward = expected_reward_for_power(
+        &h.epoch_reward_smooth,
+        &h.epoch_qa_power_smooth,
+        &sector_power,
+        EPOCHS_IN_DAY,
+    );
+    let twenty_day_reward = expected_reward_for_power(
+        &h.epoch_reward_smooth,
+        &h.epoch_qa_power_smooth,
+        &sector_power,
+        INITIAL_PLEDGE_PROJECTION_PERIOD,
+    );
+    let expected_fee = pledge_penalty_for_termination(
+        &day_reward,
+        rt.epoch - sector.activation,
+        &twenty_day_reward,
+        &h.epoch_qa_power_smooth,
+        &sector_power,
+        &h.epoch_reward_smooth,
+        &TokenAmount::zero(),
+        0,
+    );
+
+    let fee = h.get_termination_fee(&mut rt, bitfield_from_slice(&[sector.sector_number])).unwrap();
+    assert_eq!(expected_fee, fee);
+
+    // the sector is untouched
+    let state: State = rt.get_state();
+    assert!(state.get_sector(rt.store(), sector.sector_number).unwrap().is_some());
+    h.check_state(&rt);
+}
+
+#[test]
+fn termination_fee_fails_for_unknown_sector() {
+    let (h, mut rt) = setup();
+
+    rt.expect_validate_caller_any();
+    let params = GetTerminationFeeParams { sectors: bitfield_from_slice(&[100]) };
+    expect_abort_contains_message(
+        ExitCode::USR_NOT_FOUND,
+        "sector not found",
+        rt.call::<Actor>(Method::GetTerminationFee as u64, &RawBytes::serialize(params).unwrap()),
+    );
+    rt.verify();
+    h.check_state(&rt);
+}
@@ -80,6 +80,30 @@ fn report_consensus_fault_pays_reward_and_charges_fee() {
     check_state_invariants(rt.policy(), &h.get_state(&rt), rt.store(), &rt.get_balance());
 }
 
+#[test]
+fn report_consensus_fault_is_exported() {
+    let (h, mut rt) = setup();
+    rt.set_epoch(1);
+
+    let test_addr = Address::new_actor("satoshi".as_bytes());
+    let epoch = rt.epoch;
+    let receiver = rt.receiver;
+    let ret = h
+        .report_consensus_fault_exported(
+            &mut rt,
+            test_addr,
+            Some(ConsensusFault {
+                target: receiver,
+                epoch: epoch - 1,
+                fault_type: ConsensusFaultType::DoubleForkMining,
+            }),
+        )
+        .unwrap();
+    assert_eq!(epoch - 1, ret.fault_epoch);
+    assert_eq!(h.get_info(&rt).consensus_fault_elapsed, ret.ineligible_until);
+    check_state_invariants(rt.policy(), &h.get_state(&rt), rt.store(), &rt.get_balance());
+}
+
 #[test]
 fn report_consensus_fault_updates_consensus_fault_reported_field() {
     let (h, mut rt) = setup();
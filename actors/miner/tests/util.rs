@@ -22,14 +22,15 @@ use fil_actor_miner::{
     CronEventPayload, Deadline, DeadlineInfo, Deadlines, DeclareFaultsParams,
     DeclareFaultsRecoveredParams, DeferredCronEventParams, DisputeWindowedPoStParams,
     ExpirationQueue, ExpirationSet, ExtendSectorExpiration2Params, ExtendSectorExpirationParams,
-    FaultDeclaration, GetBeneficiaryReturn, GetControlAddressesReturn, Method,
-    MinerConstructorParams as ConstructorParams, MinerInfo, Partition, PendingBeneficiaryChange,
-    PoStPartition, PowerPair, PreCommitSectorBatchParams, PreCommitSectorBatchParams2,
-    PreCommitSectorParams, ProveCommitSectorParams, RecoveryDeclaration,
-    ReportConsensusFaultParams, SectorOnChainInfo, SectorPreCommitInfo, SectorPreCommitOnChainInfo,
-    Sectors, State, SubmitWindowedPoStParams, TerminateSectorsParams, TerminationDeclaration,
-    VestingFunds, WindowedPoSt, WithdrawBalanceParams, WithdrawBalanceReturn,
-    CRON_EVENT_PROVING_DEADLINE, SECTORS_AMT_BITWIDTH,
+    FaultDeclaration, GetBeneficiaryReturn, GetControlAddressesReturn, GetTerminationFeeParams,
+    GetTerminationFeeReturn, Method, MinerConstructorParams as ConstructorParams, MinerInfo,
+    Partition, PendingBeneficiaryChange, PoStPartition, PowerPair, PreCommitSectorBatchParams,
+    PreCommitSectorBatchParams2, PreCommitSectorParams, ProveCommitSectorParams,
+    RecoveryDeclaration, ReportConsensusFaultParams, ReportConsensusFaultReturn, SectorOnChainInfo,
+    SectorPreCommitInfo, SectorPreCommitOnChainInfo, Sectors, State, SubmitWindowedPoStParams,
+    TerminateSectorsParams, TerminationDeclaration, VestingFunds, WindowedPoSt,
+    WithdrawBalanceParams, WithdrawBalanceReturn, CRON_EVENT_PROVING_DEADLINE,
+    SECTORS_AMT_BITWIDTH,
 };
 use fil_actor_miner::{Method as MinerMethod, ProveCommitAggregateParams};
 use fil_actor_power::{
@@ -41,6 +42,7 @@ use fil_actor_miner::ext::verifreg::{
     Claim as FILPlusClaim, ClaimID, GetClaimsParams, GetClaimsReturn,
 };
 
+use fil_actors_runtime::bitfield::bitfield_validation_gas;
 use fil_actors_runtime::runtime::{DomainSeparationTag, Policy, Runtime, RuntimePolicy};
 use fil_actors_runtime::{test_utils::*, BatchReturn, BatchReturnGen};
 use fil_actors_runtime::{
@@ -469,6 +471,7 @@ impl ActorHarness {
         rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, addr);
         rt.expect_validate_caller_addr(self.caller_addrs());
 
+        expect_bitfield_validation(rt, &bf);
         let params = CompactSectorNumbersParams { mask_sector_numbers: bf };
 
         rt.call::<Actor>(Method::CompactSectorNumbers as u64, &RawBytes::serialize(params).unwrap())
@@ -982,6 +985,7 @@ impl ActorHarness {
         rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, self.worker);
         let addrs = self.caller_addrs().clone();
         rt.expect_validate_caller_addr(addrs);
+        expect_bitfield_validation(rt, &params.sector_numbers);
         rt.call::<Actor>(
             MinerMethod::ProveCommitAggregate as u64,
             &RawBytes::serialize(params).unwrap(),
@@ -1874,6 +1878,41 @@ impl ActorHarness {
         from: Address,
         fault: Option<ConsensusFault>,
     ) -> Result<(), ActorError> {
+        let result =
+            self.call_report_consensus_fault(rt, Method::ReportConsensusFault, from, fault)?;
+        expect_empty(result);
+        Ok(())
+    }
+
+    pub fn report_consensus_fault_exported(
+        &self,
+        rt: &mut MockRuntime,
+        from: Address,
+        fault: Option<ConsensusFault>,
+    ) -> Result<ReportConsensusFaultReturn, ActorError> {
+        let fault_epoch = fault.as_ref().map(|f| f.epoch);
+        let result: ReportConsensusFaultReturn = self
+            .call_report_consensus_fault(rt, Method::ReportConsensusFaultExported, from, fault)?
+            .deserialize()
+            .unwrap();
+        let this_epoch_reward = TokenAmount::from_atto(self.epoch_reward_smooth.estimate());
+        assert_eq!(fault_epoch, Some(result.fault_epoch));
+        assert_eq!(consensus_fault_penalty(this_epoch_reward.clone()), result.penalty);
+        assert_eq!(reward_for_consensus_slash_report(&this_epoch_reward), result.reward);
+        assert_eq!(
+            rt.epoch + rt.policy.consensus_fault_ineligibility_duration,
+            result.ineligible_until
+        );
+        Ok(result)
+    }
+
+    fn call_report_consensus_fault(
+        &self,
+        rt: &mut MockRuntime,
+        method: Method,
+        from: Address,
+        fault: Option<ConsensusFault>,
+    ) -> Result<RawBytes, ActorError> {
         rt.expect_validate_caller_type((*CALLER_TYPES_SIGNABLE).to_vec());
         rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, from);
         let params =
@@ -1922,13 +1961,9 @@ impl ActorHarness {
             ExitCode::OK,
         );
 
-        let result = rt.call::<Actor>(
-            Method::ReportConsensusFault as u64,
-            &RawBytes::serialize(params).unwrap(),
-        )?;
-        expect_empty(result);
+        let result = rt.call::<Actor>(method as u64, &RawBytes::serialize(params).unwrap())?;
         rt.verify();
-        Ok(())
+        Ok(result)
     }
 
     pub fn collect_deadline_expirations(
@@ -2252,6 +2287,15 @@ impl ActorHarness {
         Ok(())
     }
 
+    pub fn cancel_change_worker_address(&self, rt: &mut MockRuntime) -> Result<(), ActorError> {
+        rt.expect_validate_caller_addr(vec![self.owner]);
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, self.owner);
+        rt.call::<Actor>(Method::CancelChangeWorkerAddress as u64, &RawBytes::default())?;
+        rt.verify();
+
+        Ok(())
+    }
+
     pub fn propose_approve_initial_beneficiary(
         &mut self,
         rt: &mut MockRuntime,
@@ -2323,6 +2367,22 @@ impl ActorHarness {
         Ok(ret.deserialize::<GetBeneficiaryReturn>().unwrap())
     }
 
+    pub fn get_termination_fee(
+        &self,
+        rt: &mut MockRuntime,
+        sectors: BitField,
+    ) -> Result<TokenAmount, ActorError> {
+        rt.expect_validate_caller_any();
+        self.expect_query_network_info(rt);
+        let params = GetTerminationFeeParams { sectors };
+        let ret = rt.call::<Actor>(
+            Method::GetTerminationFee as u64,
+            &RawBytes::serialize(params).unwrap(),
+        )?;
+        rt.verify();
+        Ok(ret.deserialize::<GetTerminationFeeReturn>().unwrap().fee)
+    }
+
     // extend sectors without verified deals using either legacy or updated sector extension
     pub fn extend_sectors_versioned(
         &self,
@@ -2489,6 +2549,9 @@ impl ActorHarness {
         deadline: u64,
         partition: BitField,
     ) -> Result<(), ActorError> {
+        if deadline < rt.policy.wpost_period_deadlines {
+            expect_bitfield_validation(rt, &partition);
+        }
         let params = CompactPartitionsParams { deadline, partitions: partition };
 
         rt.expect_validate_caller_addr(self.caller_addrs());
@@ -2737,6 +2800,15 @@ pub fn assert_bitfield_equals(bf: &BitField, bits: &[u64]) {
     assert!(bf == &rbf);
 }
 
+/// Expects the gas charge for validating a bitfield parameter.
+#[allow(dead_code)]
+pub fn expect_bitfield_validation(rt: &mut MockRuntime, bf: &BitField) {
+    let gas = bitfield_validation_gas(bf);
+    if gas > 0 {
+        rt.expect_gas_charge(gas);
+    }
+}
+
 #[allow(dead_code)]
 pub fn make_empty_bitfield() -> BitField {
     BitField::new()
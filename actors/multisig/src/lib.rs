@@ -43,6 +43,8 @@ pub enum Method {
     ChangeNumApprovalsThreshold = 8,
     LockBalance = 9,
     UniversalReceiverHook = frc42_dispatch::method_hash!("Receive"),
+    // Read-only methods exported with FRC-42 numbers.
+    GetTransaction = frc42_dispatch::method_hash!("GetTransaction"),
 }
 
 /// Multisig Actor
@@ -446,6 +448,30 @@ impl Actor {
         execute_transaction_if_approved(rt, &st, tx_id, &txn)
     }
 
+    /// Returns a pending transaction and its proposal hash, so that signers can check
+    /// exactly what they are approving.
+    pub fn get_transaction(
+        rt: &mut impl Runtime,
+        params: GetTransactionParams,
+    ) -> Result<GetTransactionReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        let ptx = make_map_with_root::<_, Transaction>(&st.pending_txs, rt.store())
+            .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to load pending transactions")?;
+        let transaction = ptx
+            .get(&params.id.key())
+            .with_context_code(ExitCode::USR_ILLEGAL_STATE, || {
+                format!("failed to load transaction {:?}", params.id)
+            })?
+            .ok_or_else(|| actor_error!(not_found, "no such transaction {:?}", params.id))?
+            .clone();
+        let proposal_hash = compute_proposal_hash(&transaction, rt)
+            .with_context_code(ExitCode::USR_ILLEGAL_STATE, || {
+                format!("failed to compute proposal hash for (tx: {:?})", params.id)
+            })?;
+        Ok(GetTransactionReturn { transaction, proposal_hash: proposal_hash.to_vec() })
+    }
+
     // Always succeeds, accepting any transfers.
     pub fn universal_receiver_hook(
         rt: &mut impl Runtime,
@@ -597,6 +623,10 @@ impl ActorCode for Actor {
                 Self::universal_receiver_hook(rt, params)?;
                 Ok(RawBytes::default())
             }
+            Some(Method::GetTransaction) => {
+                let res = Self::get_transaction(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             None => Err(actor_error!(unhandled_message, "Invalid method")),
         }
     }
@@ -125,6 +125,24 @@ pub struct ApproveReturn {
 impl Cbor for TxnIDParams {}
 impl Cbor for ApproveReturn {}
 
+/// Parameters for fetching a pending transaction.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct GetTransactionParams {
+    pub id: TxnID,
+}
+
+/// A pending transaction together with the hash which approvals and cancellations may
+/// reference it by.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize_tuple, Deserialize_tuple)]
+pub struct GetTransactionReturn {
+    pub transaction: Transaction,
+    #[serde(with = "serde_bytes")]
+    pub proposal_hash: Vec<u8>,
+}
+
+impl Cbor for GetTransactionParams {}
+impl Cbor for GetTransactionReturn {}
+
 /// Add signer params.
 #[derive(Serialize_tuple, Deserialize_tuple)]
 pub struct AddSignerParams {
@@ -710,11 +710,14 @@ fn test_fail_propose_with_threshold_met_and_insufficient_balance() {
     h.construct_and_verify(&mut rt, num_approvals, no_unlock_duration, start_epoch, signers);
 
     rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, anne);
+    let snapshot = rt.snapshot();
     expect_abort(
         ExitCode::USR_INSUFFICIENT_FUNDS,
         h.propose(&mut rt, chuck, send_value, METHOD_SEND, fake_params),
     );
     rt.reset();
+    // The transaction recorded before the failed send is reverted.
+    rt.assert_unchanged_since(&snapshot);
     h.assert_transactions(&rt, vec![]);
     check_state(&rt);
 }
@@ -2410,6 +2413,48 @@ mod lock_balance_tests {
     }
 }
 
+#[test]
+fn get_transaction_returns_content_and_proposal_hash() {
+    let msig = Address::new_id(100);
+    let anne = Address::new_id(101);
+    let bob = Address::new_id(102);
+    let chuck = Address::new_id(103);
+
+    let mut rt = construct_runtime(msig);
+    let h = util::ActorHarness::new();
+    h.construct_and_verify(&mut rt, 2, 0, 0, vec![anne, bob]);
+
+    let fake_params = RawBytes::from(vec![1, 2, 3, 4]);
+    let fake_method = 42;
+    let send_value = TokenAmount::from_atto(10u8);
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, anne);
+    let proposal_hash =
+        h.propose_ok(&mut rt, chuck, send_value.clone(), fake_method, fake_params.clone());
+
+    // Any caller may inspect the pending transaction.
+    rt.set_caller(*MULTISIG_ACTOR_CODE_ID, Address::new_id(1000));
+    let ret = h.get_transaction(&mut rt, TxnID(0)).unwrap();
+    let expect_txn = Transaction {
+        to: chuck,
+        value: send_value.clone(),
+        method: fake_method,
+        params: fake_params.clone(),
+        approved: vec![anne],
+    };
+    assert_eq!(expect_txn, ret.transaction);
+    assert_eq!(proposal_hash.to_vec(), ret.proposal_hash);
+
+    // Approving with the same hash applies the transaction.
+    rt.set_balance(send_value.clone());
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, bob);
+    rt.expect_send(chuck, fake_method, fake_params, send_value, RawBytes::default(), ExitCode::OK);
+    h.approve_ok(&mut rt, TxnID(0), proposal_hash);
+
+    // Once applied, the transaction is no longer pending.
+    expect_abort(ExitCode::USR_NOT_FOUND, h.get_transaction(&mut rt, TxnID(0)));
+    check_state(&rt);
+}
+
 #[test]
 fn token_receiver() {
     let msig = Address::new_id(1000);
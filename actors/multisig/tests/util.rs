@@ -1,7 +1,7 @@
 use fil_actor_multisig::{
-    compute_proposal_hash, Actor, AddSignerParams, ApproveReturn, ConstructorParams, Method,
-    ProposeParams, ProposeReturn, RemoveSignerParams, State, SwapSignerParams, Transaction, TxnID,
-    TxnIDParams,
+    compute_proposal_hash, Actor, AddSignerParams, ApproveReturn, ConstructorParams,
+    GetTransactionParams, GetTransactionReturn, Method, ProposeParams, ProposeReturn,
+    RemoveSignerParams, State, SwapSignerParams, Transaction, TxnID, TxnIDParams,
 };
 use fil_actor_multisig::{ChangeNumApprovalsThresholdParams, LockBalanceParams};
 use fil_actors_runtime::test_utils::*;
@@ -163,6 +163,19 @@ impl ActorHarness {
         ret
     }
 
+    pub fn get_transaction(
+        &self,
+        rt: &mut MockRuntime,
+        txn_id: TxnID,
+    ) -> Result<GetTransactionReturn, ActorError> {
+        rt.expect_validate_caller_any();
+        let params = GetTransactionParams { id: txn_id };
+        let ret =
+            rt.call::<Actor>(Method::GetTransaction as u64, &RawBytes::serialize(params).unwrap());
+        rt.verify();
+        ret.map(|r| r.deserialize().unwrap())
+    }
+
     pub fn lock_balance(
         &self,
         rt: &mut MockRuntime,
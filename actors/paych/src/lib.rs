@@ -22,6 +22,7 @@ pub use self::types::*;
 #[cfg(feature = "fil-actor")]
 fil_actors_runtime::wasm_trampoline!(Actor);
 
+pub mod ext;
 mod state;
 pub mod testing;
 mod types;
@@ -36,6 +37,7 @@ pub enum Method {
     UpdateChannelState = 2,
     Settle = 3,
     Collect = 4,
+    UpdateChannelStates = 5,
 }
 
 pub const ERR_CHANNEL_STATE_UPDATE_AFTER_SETTLED: ExitCode = ExitCode::new(32);
@@ -49,10 +51,9 @@ impl Actor {
         // behalf of the payer/payee.
         rt.validate_immediate_caller_type(std::iter::once(&Type::Init))?;
 
-        // Check both parties are capable of signing vouchers
-        let to = Self::resolve_account(rt, &params.to)?;
+        let to = Self::resolve_party(rt, &params.to)?;
 
-        let from = Self::resolve_account(rt, &params.from)?;
+        let from = Self::resolve_party(rt, &params.from)?;
 
         let empty_arr_cid =
             Array::<(), _>::new_with_bit_width(rt.store(), LANE_STATES_AMT_BITWIDTH)
@@ -65,26 +66,14 @@ impl Actor {
         Ok(())
     }
 
-    /// Resolves an address to a canonical ID address and requires it to address an account actor.
-    fn resolve_account(rt: &mut impl Runtime, raw: &Address) -> Result<Address, ActorError> {
+    /// Resolves an address to a canonical ID address and requires it to address an existing actor.
+    /// Any actor may be a party, provided it can authenticate the vouchers it signs.
+    fn resolve_party(rt: &mut impl Runtime, raw: &Address) -> Result<Address, ActorError> {
         let resolved = resolve_to_actor_id(rt, raw)?;
-
-        let code_cid = rt
-            .get_actor_code_cid(&resolved)
-            .ok_or_else(|| actor_error!(illegal_argument, "no code for address {}", resolved))?;
-
-        let typ = rt.resolve_builtin_actor_type(&code_cid);
-        if typ != Some(Type::Account) {
-            Err(actor_error!(
-                forbidden,
-                "actor {} must be an account, was {} ({:?})",
-                raw,
-                code_cid,
-                typ
-            ))
-        } else {
-            Ok(Address::new_id(resolved))
+        if rt.get_actor_code_cid(&resolved).is_none() {
+            return Err(actor_error!(illegal_argument, "no code for address {}", resolved));
         }
+        Ok(Address::new_id(resolved))
     }
 
     pub fn update_channel_state(
@@ -95,166 +84,60 @@ impl Actor {
 
         rt.validate_immediate_caller_is([st.from, st.to].iter())?;
         let signer = if rt.message().caller() == st.from { st.to } else { st.from };
-        let sv = params.sv;
-
-        // Pull signature from signed voucher
-        let sig = sv
-            .signature
-            .as_ref()
-            .ok_or_else(|| actor_error!(illegal_argument, "voucher has no signature"))?;
-
-        if st.settling_at != 0 && rt.curr_epoch() >= st.settling_at {
-            return Err(ActorError::unchecked(
-                ERR_CHANNEL_STATE_UPDATE_AFTER_SETTLED,
-                "no vouchers can be processed after settling at epoch".to_string(),
-            ));
-        }
+        validate_voucher(rt, &st, &signer, &params)?;
 
-        if params.secret.len() > MAX_SECRET_SIZE {
-            return Err(actor_error!(illegal_argument, "secret must be at most 256 bytes long"));
-        }
-
-        // Generate unsigned bytes
-        let sv_bz = sv.signing_bytes().map_err(|e| {
-            ActorError::serialization(format!("failed to serialized SignedVoucher: {}", e))
-        })?;
+        rt.transaction(|st: &mut State, rt| {
+            let balance = rt.current_balance();
+            let mut l_states = Array::load(&st.lane_states, rt.store()).map_err(|e| {
+                e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load lane states")
+            })?;
 
-        // Validate signature
-        rt.verify_signature(sig, &signer, &sv_bz).map_err(|e| {
-            e.downcast_default(ExitCode::USR_ILLEGAL_ARGUMENT, "voucher signature invalid")
-        })?;
+            redeem_voucher(st, &mut l_states, &balance, params.sv)?;
 
-        let pch_addr = rt.message().receiver();
-        let svpch_id = rt.resolve_address(&sv.channel_addr).ok_or_else(|| {
-            actor_error!(
-                illegal_argument,
-                "voucher payment channel address {} does not resolve to an ID address",
-                sv.channel_addr
-            )
-        })?;
-        if pch_addr != Address::new_id(svpch_id) {
-            return Err(actor_error!(illegal_argument;
-                    "voucher payment channel address {} does not match receiver {}",
-                    svpch_id, pch_addr));
-        }
-
-        if rt.curr_epoch() < sv.time_lock_min {
-            return Err(actor_error!(illegal_argument; "cannot use this voucher yet"));
-        }
+            st.lane_states = l_states.flush().map_err(|e| {
+                e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to save lanes")
+            })?;
+            Ok(())
+        })
+    }
 
-        if sv.time_lock_max != 0 && rt.curr_epoch() > sv.time_lock_max {
-            return Err(actor_error!(illegal_argument; "this voucher has expired"));
-        }
+    /// Redeems several vouchers, possibly on different lanes, in a single message.
+    /// Vouchers are redeemed in order, with the same checks as UpdateChannelState,
+    /// and either all of them are redeemed or none are.
+    pub fn update_channel_states(
+        rt: &mut impl Runtime,
+        params: UpdateChannelStatesParams,
+    ) -> Result<(), ActorError> {
+        let st: State = rt.state()?;
 
-        if sv.amount.is_negative() {
-            return Err(actor_error!(illegal_argument;
-                    "voucher amount must be non-negative, was {}", sv.amount));
+        rt.validate_immediate_caller_is([st.from, st.to].iter())?;
+        if params.updates.is_empty() {
+            return Err(actor_error!(illegal_argument, "no vouchers to redeem"));
         }
-
-        if !sv.secret_pre_image.is_empty() {
-            let hashed_secret: &[u8] = &rt.hash_blake2b(&params.secret);
-            if hashed_secret != sv.secret_pre_image.as_slice() {
-                return Err(actor_error!(illegal_argument; "incorrect secret"));
-            }
+        let max_size = rt.policy().paych_updates_batch_max_size;
+        if params.updates.len() > max_size {
+            return Err(actor_error!(
+                illegal_argument,
+                "batch of {} vouchers exceeds maximum of {}",
+                params.updates.len(),
+                max_size
+            ));
         }
-
-        if let Some(extra) = &sv.extra {
-            rt.send(&extra.actor, extra.method, extra.data.clone(), TokenAmount::zero())
-                .map_err(|e| e.wrap("spend voucher verification failed"))?;
+        let signer = if rt.message().caller() == st.from { st.to } else { st.from };
+        for update in &params.updates {
+            validate_voucher(rt, &st, &signer, update)?;
         }
 
         rt.transaction(|st: &mut State, rt| {
+            let balance = rt.current_balance();
             let mut l_states = Array::load(&st.lane_states, rt.store()).map_err(|e| {
                 e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load lane states")
             })?;
 
-            // Find the voucher lane, create and insert it in sorted order if necessary.
-            let lane_id = sv.lane;
-            let lane_state = find_lane(&l_states, lane_id)?;
-
-            let mut lane_state = if let Some(state) = lane_state {
-                if state.nonce >= sv.nonce {
-                    return Err(actor_error!(illegal_argument;
-                        "voucher has an outdated nonce, existing: {}, voucher: {}, cannot redeem",
-                        state.nonce, sv.nonce));
-                }
-                state.clone()
-            } else {
-                LaneState::default()
-            };
-
-            // The next section actually calculates the payment amounts to update
-            // the payment channel state
-            // 1. (optional) sum already redeemed value of all merging lanes
-            let mut redeemed_from_others = TokenAmount::zero();
-            for merge in sv.merges {
-                if merge.lane == sv.lane {
-                    return Err(actor_error!(illegal_argument;
-                        "voucher cannot merge lanes into it's own lane"));
-                }
-                let mut other_ls = find_lane(&l_states, merge.lane)?
-                    .ok_or_else(|| {
-                        actor_error!(illegal_argument;
-                        "voucher specifies invalid merge lane {}", merge.lane)
-                    })?
-                    .clone();
-
-                if other_ls.nonce >= merge.nonce {
-                    return Err(actor_error!(illegal_argument;
-                            "merged lane in voucher has outdated nonce, cannot redeem"));
-                }
-
-                redeemed_from_others += &other_ls.redeemed;
-                other_ls.nonce = merge.nonce;
-                l_states.set(merge.lane, other_ls).map_err(|e| {
-                    e.downcast_default(
-                        ExitCode::USR_ILLEGAL_STATE,
-                        format!("failed to store lane {}", merge.lane),
-                    )
-                })?;
-            }
-
-            // 2. To prevent double counting, remove already redeemed amounts (from
-            // voucher or other lanes) from the voucher amount
-            lane_state.nonce = sv.nonce;
-            let balance_delta = &sv.amount - (redeemed_from_others + &lane_state.redeemed);
-
-            // 3. set new redeemed value for merged-into lane
-            lane_state.redeemed = sv.amount;
-
-            // 4. check operation validity
-            let new_send_balance = balance_delta + &st.to_send;
-
-            if new_send_balance < TokenAmount::zero() {
-                return Err(actor_error!(illegal_argument;
-                    "voucher would leave channel balance negative"));
-            }
-
-            if new_send_balance > rt.current_balance() {
-                return Err(actor_error!(illegal_argument;
-                    "not enough funds in channel to cover voucher"));
-            }
-
-            // 5. add new redemption ToSend
-            st.to_send = new_send_balance;
-
-            // update channel settlingAt and MinSettleHeight if delayed by voucher
-            if sv.min_settle_height != 0 {
-                if st.settling_at != 0 && st.settling_at < sv.min_settle_height {
-                    st.settling_at = sv.min_settle_height;
-                }
-                if st.min_settle_height < sv.min_settle_height {
-                    st.min_settle_height = sv.min_settle_height;
-                }
+            for update in params.updates {
+                redeem_voucher(st, &mut l_states, &balance, update.sv)?;
             }
 
-            l_states.set(lane_id, lane_state).map_err(|e| {
-                e.downcast_default(
-                    ExitCode::USR_ILLEGAL_STATE,
-                    format!("failed to store lane {}", lane_id),
-                )
-            })?;
-
             st.lane_states = l_states.flush().map_err(|e| {
                 e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to save lanes")
             })?;
@@ -298,6 +181,189 @@ impl Actor {
     }
 }
 
+/// Checks a voucher's signature, channel, time locks, amount, secret and extra verification
+/// against the channel state, without redeeming it.
+fn validate_voucher(
+    rt: &mut impl Runtime,
+    st: &State,
+    signer: &Address,
+    params: &UpdateChannelStateParams,
+) -> Result<(), ActorError> {
+    let sv = &params.sv;
+
+    // Pull signature from signed voucher
+    let sig = sv
+        .signature
+        .as_ref()
+        .ok_or_else(|| actor_error!(illegal_argument, "voucher has no signature"))?;
+
+    if st.settling_at != 0 && rt.curr_epoch() >= st.settling_at {
+        return Err(ActorError::unchecked(
+            ERR_CHANNEL_STATE_UPDATE_AFTER_SETTLED,
+            "no vouchers can be processed after settling at epoch".to_string(),
+        ));
+    }
+
+    if params.secret.len() > MAX_SECRET_SIZE {
+        return Err(actor_error!(illegal_argument, "secret must be at most 256 bytes long"));
+    }
+
+    // Generate unsigned bytes
+    let sv_bz = sv.signing_bytes().map_err(|e| {
+        ActorError::serialization(format!("failed to serialized SignedVoucher: {}", e))
+    })?;
+
+    // Validate signature with the signer, which may be an account or any other actor
+    // implementing message authentication.
+    rt.send(
+        signer,
+        ext::account::AUTHENTICATE_MESSAGE_METHOD,
+        RawBytes::serialize(ext::account::AuthenticateMessageParams {
+            signature: sig.bytes.clone(),
+            message: sv_bz,
+        })?,
+        TokenAmount::zero(),
+    )
+    .map_err(|e| e.wrap("voucher signature invalid"))?;
+
+    let pch_addr = rt.message().receiver();
+    let svpch_id = rt.resolve_address(&sv.channel_addr).ok_or_else(|| {
+        actor_error!(
+            illegal_argument,
+            "voucher payment channel address {} does not resolve to an ID address",
+            sv.channel_addr
+        )
+    })?;
+    if pch_addr != Address::new_id(svpch_id) {
+        return Err(actor_error!(illegal_argument;
+                "voucher payment channel address {} does not match receiver {}",
+                svpch_id, pch_addr));
+    }
+
+    if rt.curr_epoch() < sv.time_lock_min {
+        return Err(actor_error!(illegal_argument; "cannot use this voucher yet"));
+    }
+
+    if sv.time_lock_max != 0 && rt.curr_epoch() > sv.time_lock_max {
+        return Err(actor_error!(illegal_argument; "this voucher has expired"));
+    }
+
+    if sv.amount.is_negative() {
+        return Err(actor_error!(illegal_argument;
+                "voucher amount must be non-negative, was {}", sv.amount));
+    }
+
+    if !sv.secret_pre_image.is_empty() {
+        let hashed_secret: &[u8] = &rt.hash_blake2b(&params.secret);
+        if hashed_secret != sv.secret_pre_image.as_slice() {
+            return Err(actor_error!(illegal_argument; "incorrect secret"));
+        }
+    }
+
+    if let Some(extra) = &sv.extra {
+        rt.send(&extra.actor, extra.method, extra.data.clone(), TokenAmount::zero())
+            .map_err(|e| e.wrap("spend voucher verification failed"))?;
+    }
+
+    Ok(())
+}
+
+/// Redeems a validated voucher against the lane states, updating the amount to send.
+fn redeem_voucher<BS>(
+    st: &mut State,
+    l_states: &mut Array<LaneState, BS>,
+    balance: &TokenAmount,
+    sv: SignedVoucher,
+) -> Result<(), ActorError>
+where
+    BS: Blockstore,
+{
+    // Find the voucher lane, create and insert it in sorted order if necessary.
+    let lane_id = sv.lane;
+    let lane_state = find_lane(l_states, lane_id)?;
+
+    let mut lane_state = if let Some(state) = lane_state {
+        if state.nonce >= sv.nonce {
+            return Err(actor_error!(illegal_argument;
+                "voucher has an outdated nonce, existing: {}, voucher: {}, cannot redeem",
+                state.nonce, sv.nonce));
+        }
+        state.clone()
+    } else {
+        LaneState::default()
+    };
+
+    // The next section actually calculates the payment amounts to update
+    // the payment channel state
+    // 1. (optional) sum already redeemed value of all merging lanes
+    let mut redeemed_from_others = TokenAmount::zero();
+    for merge in sv.merges {
+        if merge.lane == sv.lane {
+            return Err(actor_error!(illegal_argument;
+                "voucher cannot merge lanes into it's own lane"));
+        }
+        let mut other_ls = find_lane(l_states, merge.lane)?
+            .ok_or_else(|| {
+                actor_error!(illegal_argument;
+                "voucher specifies invalid merge lane {}", merge.lane)
+            })?
+            .clone();
+
+        if other_ls.nonce >= merge.nonce {
+            return Err(actor_error!(illegal_argument;
+                    "merged lane in voucher has outdated nonce, cannot redeem"));
+        }
+
+        redeemed_from_others += &other_ls.redeemed;
+        other_ls.nonce = merge.nonce;
+        l_states.set(merge.lane, other_ls).map_err(|e| {
+            e.downcast_default(
+                ExitCode::USR_ILLEGAL_STATE,
+                format!("failed to store lane {}", merge.lane),
+            )
+        })?;
+    }
+
+    // 2. To prevent double counting, remove already redeemed amounts (from
+    // voucher or other lanes) from the voucher amount
+    lane_state.nonce = sv.nonce;
+    let balance_delta = &sv.amount - (redeemed_from_others + &lane_state.redeemed);
+
+    // 3. set new redeemed value for merged-into lane
+    lane_state.redeemed = sv.amount;
+
+    // 4. check operation validity
+    let new_send_balance = balance_delta + &st.to_send;
+
+    if new_send_balance < TokenAmount::zero() {
+        return Err(actor_error!(illegal_argument;
+            "voucher would leave channel balance negative"));
+    }
+
+    if new_send_balance > *balance {
+        return Err(actor_error!(illegal_argument;
+            "not enough funds in channel to cover voucher"));
+    }
+
+    // 5. add new redemption ToSend
+    st.to_send = new_send_balance;
+
+    // update channel settlingAt and MinSettleHeight if delayed by voucher
+    if sv.min_settle_height != 0 {
+        if st.settling_at != 0 && st.settling_at < sv.min_settle_height {
+            st.settling_at = sv.min_settle_height;
+        }
+        if st.min_settle_height < sv.min_settle_height {
+            st.min_settle_height = sv.min_settle_height;
+        }
+    }
+
+    l_states.set(lane_id, lane_state).map_err(|e| {
+        e.downcast_default(ExitCode::USR_ILLEGAL_STATE, format!("failed to store lane {}", lane_id))
+    })?;
+    Ok(())
+}
+
 #[inline]
 fn find_lane<'a, BS>(
     ls: &'a Array<LaneState, BS>,
@@ -333,6 +399,10 @@ impl ActorCode for Actor {
                 Self::update_channel_state(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::default())
             }
+            Some(Method::UpdateChannelStates) => {
+                Self::update_channel_states(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::default())
+            }
             Some(Method::Settle) => {
                 Self::settle(rt)?;
                 Ok(RawBytes::default())
@@ -125,3 +125,8 @@ impl From<SignedVoucher> for UpdateChannelStateParams {
         UpdateChannelStateParams { secret: vec![], sv }
     }
 }
+
+#[derive(Serialize_tuple, Deserialize_tuple)]
+pub struct UpdateChannelStatesParams {
+    pub updates: Vec<UpdateChannelStateParams>,
+}
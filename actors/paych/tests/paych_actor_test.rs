@@ -4,13 +4,14 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 
-use anyhow::anyhow;
 use cid::Cid;
 use derive_builder::Builder;
+use fil_actor_paych::ext::account::{AuthenticateMessageParams, AUTHENTICATE_MESSAGE_METHOD};
 use fil_actor_paych::testing::check_state_invariants;
 use fil_actor_paych::{
     Actor as PaychActor, ConstructorParams, LaneState, Merge, Method, ModVerifyParams,
-    SignedVoucher, State as PState, UpdateChannelStateParams, MAX_LANE, SETTLE_DELAY,
+    SignedVoucher, State as PState, UpdateChannelStateParams, UpdateChannelStatesParams, MAX_LANE,
+    SETTLE_DELAY,
 };
 use fil_actors_runtime::runtime::builtins::Type;
 use fil_actors_runtime::runtime::Runtime;
@@ -147,37 +148,41 @@ mod paych_constructor {
         let caller_addr = Address::new_id(TEST_CALLER_ADDR);
 
         struct TestCase {
-            from_code: Cid,
+            from_code: Option<Cid>,
             from_addr: Address,
-            to_code: Cid,
+            to_code: Option<Cid>,
             to_addr: Address,
             expected_exit_code: ExitCode,
         }
 
         let test_cases: Vec<TestCase> = vec![
-            // fails if target (to) is not account actor
+            // fails if target (to) is not an actor
             TestCase {
-                from_code: *ACCOUNT_ACTOR_CODE_ID,
+                from_code: Some(*ACCOUNT_ACTOR_CODE_ID),
                 from_addr: payer_addr,
-                to_code: *MULTISIG_ACTOR_CODE_ID,
+                to_code: None,
                 to_addr: payee_addr,
-                expected_exit_code: ExitCode::USR_FORBIDDEN,
+                expected_exit_code: ExitCode::USR_ILLEGAL_ARGUMENT,
             },
-            // fails if sender (from) is not account actor
+            // fails if sender (from) is not an actor
             TestCase {
-                from_code: *MULTISIG_ACTOR_CODE_ID,
+                from_code: None,
                 from_addr: payer_addr,
-                to_code: *ACCOUNT_ACTOR_CODE_ID,
+                to_code: Some(*MULTISIG_ACTOR_CODE_ID),
                 to_addr: payee_addr,
-                expected_exit_code: ExitCode::USR_FORBIDDEN,
+                expected_exit_code: ExitCode::USR_ILLEGAL_ARGUMENT,
             },
         ];
 
         for test_case in test_cases {
             let mut actor_code_cids = HashMap::default();
             actor_code_cids.insert(paych_addr, *PAYCH_ACTOR_CODE_ID);
-            actor_code_cids.insert(test_case.to_addr, test_case.to_code);
-            actor_code_cids.insert(test_case.from_addr, test_case.from_code);
+            if let Some(to_code) = test_case.to_code {
+                actor_code_cids.insert(test_case.to_addr, to_code);
+            }
+            if let Some(from_code) = test_case.from_code {
+                actor_code_cids.insert(test_case.from_addr, from_code);
+            }
 
             let mut rt = MockRuntime {
                 receiver: paych_addr,
@@ -198,6 +203,18 @@ mod paych_constructor {
         }
     }
 
+    #[test]
+    fn create_paych_actor_with_non_account_parties() {
+        // Parties need not be accounts, since vouchers are authenticated by the signing actor.
+        let payer_addr = Address::new_id(TEST_PAYER_ADDR);
+        let payee_addr = Address::new_id(103);
+        let mut rt = construct_runtime();
+        rt.actor_code_cids.insert(payer_addr, *MULTISIG_ACTOR_CODE_ID);
+        rt.actor_code_cids.insert(payee_addr, *MULTISIG_ACTOR_CODE_ID);
+        construct_and_verify(&mut rt, payer_addr, payee_addr);
+        check_state(&rt);
+    }
+
     #[test]
     fn sendr_addr_not_resolvable_to_id_addr() {
         const TO_ADDR: u64 = 101;
@@ -444,14 +461,18 @@ mod create_lane_tests {
             rt.expect_validate_caller_addr(vec![payer_addr, payee_addr]);
 
             if test_case.sig.is_some() && test_case.secret_preimage.is_empty() {
-                let exp_exit_code =
-                    if !test_case.verify_sig { Err(anyhow!("bad signature")) } else { Ok(()) };
-                rt.expect_verify_signature(ExpectedVerifySig {
-                    sig: sv.clone().signature.unwrap(),
-                    signer: payer_addr,
-                    plaintext: sv.signing_bytes().unwrap(),
-                    result: exp_exit_code,
-                });
+                let exp_exit_code = if !test_case.verify_sig {
+                    ExitCode::USR_ILLEGAL_ARGUMENT
+                } else {
+                    ExitCode::OK
+                };
+                expect_authenticate_message(
+                    &mut rt,
+                    payer_addr,
+                    sv.clone().signature.unwrap(),
+                    sv.signing_bytes().unwrap(),
+                    exp_exit_code,
+                );
             }
 
             if test_case.exp_exit_code == ExitCode::OK {
@@ -499,12 +520,13 @@ mod update_channel_state_redeem {
 
         let payer_addr = Address::new_id(PAYER_ID);
 
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: payer_addr,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            payer_addr,
+            sv.clone().signature.unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
 
         call(
             &mut rt,
@@ -525,6 +547,62 @@ mod update_channel_state_redeem {
         verify_state(&rt, Some(1), exp_state);
     }
 
+    #[test]
+    fn redeem_voucher_fails_if_signer_cannot_authenticate() {
+        let paych_addr = Address::new_id(PAYCH_ID);
+        let payer_addr = Address::new_id(PAYER_ID);
+        let payee_addr = Address::new_id(PAYEE_ID);
+
+        // The payee is a multisig, which doesn't implement message authentication.
+        let mut actor_code_cids = HashMap::default();
+        actor_code_cids.insert(payer_addr, *ACCOUNT_ACTOR_CODE_ID);
+        actor_code_cids.insert(payee_addr, *MULTISIG_ACTOR_CODE_ID);
+        let mut rt = MockRuntime {
+            receiver: paych_addr,
+            caller: INIT_ACTOR_ADDR,
+            caller_type: *INIT_ACTOR_CODE_ID,
+            actor_code_cids,
+            balance: RefCell::new(TokenAmount::from_atto(100_000)),
+            epoch: 2,
+            ..Default::default()
+        };
+        construct_and_verify(&mut rt, payer_addr, payee_addr);
+
+        let sig = Signature::new_bls(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        let sv = SignedVoucher {
+            time_lock_min: 0,
+            time_lock_max: i64::MAX,
+            lane: 0,
+            nonce: 1,
+            amount: TokenAmount::from_atto(1),
+            signature: Some(sig.clone()),
+            secret_pre_image: Default::default(),
+            channel_addr: paych_addr,
+            extra: Default::default(),
+            min_settle_height: Default::default(),
+            merges: Default::default(),
+        };
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, payer_addr);
+        rt.expect_validate_caller_addr(vec![payer_addr, payee_addr]);
+        // The multisig rejects the exported AuthenticateMessage number as an unknown method.
+        expect_authenticate_message(
+            &mut rt,
+            payee_addr,
+            sig,
+            sv.signing_bytes().unwrap(),
+            ExitCode::USR_UNHANDLED_MESSAGE,
+        );
+        expect_abort(
+            &mut rt,
+            Method::UpdateChannelState as u64,
+            &RawBytes::serialize(UpdateChannelStateParams::from(sv)).unwrap(),
+            ExitCode::USR_UNHANDLED_MESSAGE,
+        );
+        rt.verify();
+        let state: PState = rt.get_state();
+        assert_eq!(TokenAmount::zero(), state.to_send);
+    }
+
     #[test]
     fn redeem_voucher_correct_lane() {
         let (mut rt, mut sv) = require_create_channel_with_lanes(3);
@@ -542,12 +620,13 @@ mod update_channel_state_redeem {
         sv.nonce = ls_to_update.nonce + 1;
         let payer_addr = Address::new_id(PAYER_ID);
 
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: payer_addr,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            payer_addr,
+            sv.clone().signature.unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
 
         call(
             &mut rt,
@@ -582,12 +661,13 @@ mod update_channel_state_redeem {
 
         let payer_addr = Address::new_id(PAYER_ID);
 
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: payer_addr,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            payer_addr,
+            sv.clone().signature.unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
 
         expect_abort(
             &mut rt,
@@ -601,6 +681,117 @@ mod update_channel_state_redeem {
     }
 }
 
+mod update_channel_states {
+    use super::*;
+
+    // Returns vouchers for lanes 0 and 1 of a two-lane channel, redeeming 5 and 7 respectively.
+    fn two_lane_vouchers() -> (MockRuntime, SignedVoucher, SignedVoucher) {
+        let (rt, sv) = require_create_channel_with_lanes(2);
+        let state: PState = rt.get_state();
+        let lane0 = get_lane_state(&rt, &state.lane_states, 0);
+        let lane1 = get_lane_state(&rt, &state.lane_states, 1);
+
+        let sv0 = SignedVoucher {
+            lane: 0,
+            nonce: lane0.nonce + 1,
+            amount: TokenAmount::from_atto(5),
+            ..sv.clone()
+        };
+        let sv1 = SignedVoucher {
+            lane: 1,
+            nonce: lane1.nonce + 1,
+            amount: TokenAmount::from_atto(7),
+            ..sv
+        };
+        (rt, sv0, sv1)
+    }
+
+    // Expects the payee to submit the vouchers, each signed by the payer.
+    fn expect_redeem(rt: &mut MockRuntime, vouchers: &[&SignedVoucher]) {
+        let state: PState = rt.get_state();
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(PAYEE_ID));
+        rt.expect_validate_caller_addr(vec![state.from, state.to]);
+        for sv in vouchers {
+            expect_authenticate_message(
+                rt,
+                Address::new_id(PAYER_ID),
+                sv.signature.clone().unwrap(),
+                sv.signing_bytes().unwrap(),
+                ExitCode::OK,
+            );
+        }
+    }
+
+    #[test]
+    fn redeem_vouchers_across_lanes() {
+        let (mut rt, sv0, sv1) = two_lane_vouchers();
+        let initial: PState = rt.get_state();
+        expect_redeem(&mut rt, &[&sv0, &sv1]);
+
+        let params =
+            UpdateChannelStatesParams { updates: vec![sv0.clone().into(), sv1.clone().into()] };
+        call(&mut rt, Method::UpdateChannelStates as u64, &RawBytes::serialize(params).unwrap());
+        rt.verify();
+
+        // Lanes 0 and 1 had already redeemed 1 and 2.
+        let state: PState = rt.get_state();
+        assert_eq!(initial.to_send + TokenAmount::from_atto(4 + 5), state.to_send);
+        for sv in [sv0, sv1] {
+            let ls = get_lane_state(&rt, &state.lane_states, sv.lane);
+            assert_eq!(LaneState { redeemed: sv.amount, nonce: sv.nonce }, ls);
+        }
+        check_state(&rt);
+    }
+
+    #[test]
+    fn batch_fails_atomically() {
+        let (mut rt, sv0, mut sv1) = two_lane_vouchers();
+        let initial: PState = rt.get_state();
+
+        // The second voucher repeats a nonce already used on its lane.
+        sv1.nonce -= 1;
+        expect_redeem(&mut rt, &[&sv0, &sv1]);
+
+        let params = UpdateChannelStatesParams { updates: vec![sv0.into(), sv1.into()] };
+        expect_abort(
+            &mut rt,
+            Method::UpdateChannelStates as u64,
+            &RawBytes::serialize(params).unwrap(),
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+        );
+        rt.verify();
+
+        let state: PState = rt.get_state();
+        assert_eq!(initial.to_send, state.to_send);
+        assert_eq!(initial.lane_states, state.lane_states);
+        check_state(&rt);
+    }
+
+    #[test]
+    fn batch_exceeding_max_size_rejected() {
+        let (mut rt, sv0, sv1) = two_lane_vouchers();
+        rt.policy.paych_updates_batch_max_size = 1;
+        let initial: PState = rt.get_state();
+
+        // The batch is rejected before any voucher is authenticated.
+        expect_redeem(&mut rt, &[]);
+
+        let params = UpdateChannelStatesParams { updates: vec![sv0.into(), sv1.into()] };
+        expect_abort(
+            &mut rt,
+            Method::UpdateChannelStates as u64,
+            &RawBytes::serialize(params).unwrap(),
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+        );
+        rt.verify();
+
+        let state: PState = rt.get_state();
+        assert_eq!(initial.to_send, state.to_send);
+        assert_eq!(initial.lane_states, state.lane_states);
+        check_state(&rt);
+    }
+}
+
 mod merge_tests {
     use super::*;
 
@@ -614,12 +805,13 @@ mod merge_tests {
 
     fn failure_end(rt: &mut MockRuntime, sv: SignedVoucher, exp_exit_code: ExitCode) {
         let payee_addr = Address::new_id(PAYEE_ID);
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: payee_addr,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            rt,
+            payee_addr,
+            sv.clone().signature.unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
         expect_abort(
             rt,
             Method::UpdateChannelState as u64,
@@ -642,12 +834,13 @@ mod merge_tests {
 
         sv.merges = vec![Merge { lane: 1, nonce: merge_nonce }];
         let payee_addr = Address::new_id(PAYEE_ID);
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: payee_addr,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            payee_addr,
+            sv.clone().signature.unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
 
         call(
             &mut rt,
@@ -767,12 +960,13 @@ mod update_channel_state_extra {
             method: Method::UpdateChannelState as u64,
             data: fake_params.clone(),
         });
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: state.to,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            state.to,
+            sv.clone().signature.unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
 
         rt.expect_send(
             other_addr,
@@ -849,12 +1043,13 @@ fn update_channel_settling() {
     for tc in test_cases {
         ucp.sv.min_settle_height = tc.min_settle;
         rt.expect_validate_caller_addr(vec![state.from, state.to]);
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: state.to,
-            plaintext: ucp.sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            state.to,
+            sv.clone().signature.unwrap(),
+            ucp.sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
         call(&mut rt, Method::UpdateChannelState as u64, &RawBytes::serialize(&ucp).unwrap());
         let new_state: PState = rt.get_state();
         assert_eq!(tc.exp_settling_at, new_state.settling_at);
@@ -875,12 +1070,13 @@ mod secret_preimage {
 
         let ucp = UpdateChannelStateParams::from(sv.clone());
 
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.clone().signature.unwrap(),
-            signer: state.to,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            state.to,
+            sv.clone().signature.unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
 
         call(&mut rt, Method::UpdateChannelState as u64, &RawBytes::serialize(ucp).unwrap());
 
@@ -900,12 +1096,13 @@ mod secret_preimage {
         ucp.sv.secret_pre_image = mag;
 
         rt.expect_validate_caller_addr(vec![state.from, state.to]);
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: sv.signature.unwrap(),
-            signer: state.to,
-            plaintext: ucp.sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            state.to,
+            sv.signature.unwrap(),
+            ucp.sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
         expect_abort(
             &mut rt,
             Method::UpdateChannelState as u64,
@@ -968,12 +1165,13 @@ mod actor_settle {
         let ucp = UpdateChannelStateParams::from(sv.clone());
 
         rt.expect_validate_caller_addr(vec![state.from, state.to]);
-        rt.expect_verify_signature(ExpectedVerifySig {
-            sig: ucp.sv.signature.clone().unwrap(),
-            signer: state.to,
-            plaintext: sv.signing_bytes().unwrap(),
-            result: Ok(()),
-        });
+        expect_authenticate_message(
+            &mut rt,
+            state.to,
+            ucp.sv.signature.clone().unwrap(),
+            sv.signing_bytes().unwrap(),
+            ExitCode::OK,
+        );
         call(&mut rt, Method::UpdateChannelState as u64, &RawBytes::serialize(&ucp).unwrap());
 
         state = rt.get_state();
@@ -1180,12 +1378,7 @@ fn require_add_new_lane(rt: &mut MockRuntime, param: LaneParams) -> SignedVouche
     };
     rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, param.from);
     rt.expect_validate_caller_addr(vec![param.from, param.to]);
-    rt.expect_verify_signature(ExpectedVerifySig {
-        sig,
-        signer: payee_addr,
-        plaintext: sv.signing_bytes().unwrap(),
-        result: Ok(()),
-    });
+    expect_authenticate_message(rt, payee_addr, sig, sv.signing_bytes().unwrap(), ExitCode::OK);
     call(
         rt,
         Method::UpdateChannelState as u64,
@@ -1196,6 +1389,24 @@ fn require_add_new_lane(rt: &mut MockRuntime, param: LaneParams) -> SignedVouche
     sv
 }
 
+fn expect_authenticate_message(
+    rt: &mut MockRuntime,
+    signer: Address,
+    sig: Signature,
+    plaintext: Vec<u8>,
+    exit_code: ExitCode,
+) {
+    rt.expect_send(
+        signer,
+        AUTHENTICATE_MESSAGE_METHOD,
+        RawBytes::serialize(AuthenticateMessageParams { signature: sig.bytes, message: plaintext })
+            .unwrap(),
+        TokenAmount::zero(),
+        RawBytes::default(),
+        exit_code,
+    );
+}
+
 fn construct_and_verify(rt: &mut MockRuntime, sender: Address, receiver: Address) {
     let params = ConstructorParams { from: sender, to: receiver };
     rt.expect_validate_caller_type(vec![Type::Init]);
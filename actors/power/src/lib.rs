@@ -18,7 +18,7 @@ use fvm_shared::bigint::bigint_ser::BigIntSer;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::ExitCode;
 use fvm_shared::reward::ThisEpochRewardReturn;
-use fvm_shared::sector::SealVerifyInfo;
+use fvm_shared::sector::{SealVerifyInfo, StoragePower};
 use fvm_shared::{MethodNum, HAMT_BIT_WIDTH, METHOD_CONSTRUCTOR};
 use log::{debug, error};
 use num_derive::FromPrimitive;
@@ -61,6 +61,11 @@ pub enum Method {
     // OnConsensusFault = 7,
     SubmitPoRepForBulkVerify = 8,
     CurrentTotalPower = 9,
+    // Read-only methods exported with FRC-42 numbers.
+    MinerPower = frc42_dispatch::method_hash!("MinerPower"),
+    NetworkQAPower = frc42_dispatch::method_hash!("NetworkQAPower"),
+    TotalPledgeCollateral = frc42_dispatch::method_hash!("TotalPledgeCollateral"),
+    MinerConsensusEligibility = frc42_dispatch::method_hash!("MinerConsensusEligibility"),
 }
 
 pub const ERR_TOO_MANY_PROVE_COMMITS: ExitCode = ExitCode::new(32);
@@ -364,6 +369,64 @@ impl Actor {
         })
     }
 
+    /// Returns the raw byte and quality-adjusted power claimed by a miner.
+    fn miner_power(
+        rt: &mut impl Runtime,
+        params: MinerPowerParams,
+    ) -> Result<MinerPowerReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        let claim = st
+            .get_claim(rt.store(), &Address::new_id(params.miner))
+            .map_err(|e| e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to get claim"))?
+            .ok_or_else(|| actor_error!(not_found, "no claim for miner {}", params.miner))?;
+
+        Ok(MinerPowerReturn {
+            raw_byte_power: claim.raw_byte_power,
+            quality_adj_power: claim.quality_adj_power,
+        })
+    }
+
+    /// Returns whether a miner has a power claim, and whether that claim meets the minimum
+    /// for the miner to participate in consensus.
+    /// A missing claim is reported in the return value rather than as an error.
+    fn miner_consensus_eligibility(
+        rt: &mut impl Runtime,
+        params: MinerConsensusEligibilityParams,
+    ) -> Result<MinerConsensusEligibilityReturn, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        let miner = Address::new_id(params.miner);
+        let has_claim = st
+            .get_claim(rt.store(), &miner)
+            .map_err(|e| e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to get claim"))?
+            .is_some();
+        let eligible = has_claim
+            && st
+                .miner_nominal_power_meets_consensus_minimum(rt.policy(), rt.store(), &miner)
+                .map_err(|e| {
+                    e.downcast_default(
+                        ExitCode::USR_ILLEGAL_STATE,
+                        "failed to check miner consensus power",
+                    )
+                })?;
+        Ok(MinerConsensusEligibilityReturn { has_claim, eligible })
+    }
+
+    /// Returns the network's total quality-adjusted power as of this epoch.
+    fn network_qa_power(rt: &mut impl Runtime) -> Result<StoragePower, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        Ok(st.this_epoch_quality_adj_power)
+    }
+
+    /// Returns the total pledge collateral locked by all miners as of this epoch.
+    fn total_pledge_collateral(rt: &mut impl Runtime) -> Result<TokenAmount, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        Ok(st.this_epoch_pledge_collateral)
+    }
+
     fn process_batch_proof_verifies(
         rt: &mut impl Runtime,
         rewret: &ThisEpochRewardReturn,
@@ -519,8 +582,9 @@ impl Actor {
                     .map_err(|e| {
                         e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to load claims")
                     })?;
+            let mut next_cron_epoch = rt_epoch + 1;
             for epoch in st.first_cron_epoch..=rt_epoch {
-                let epoch_events = load_cron_events(&events, epoch).map_err(|e| {
+                let mut epoch_events = load_cron_events(&events, epoch).map_err(|e| {
                     e.downcast_default(
                         ExitCode::USR_ILLEGAL_STATE,
                         format!("failed to load cron events at {}", epoch),
@@ -531,6 +595,15 @@ impl Actor {
                     continue;
                 }
 
+                // Dequeue at most the remaining per-tick allowance from this epoch, leaving the
+                // rest queued for the next tick.
+                let allowance = MAX_CRON_EVENTS_PER_TICK - cron_events.len();
+                let deferred = if epoch_events.len() > allowance {
+                    epoch_events.split_off(allowance)
+                } else {
+                    Vec::new()
+                };
+
                 for evt in epoch_events.into_iter() {
                     let miner_has_claim =
                         claims.contains_key(&evt.miner_addr.to_bytes()).map_err(|e| {
@@ -552,9 +625,26 @@ impl Actor {
                         format!("failed to clear cron events at {}", epoch),
                     )
                 })?;
+
+                if !deferred.is_empty() {
+                    for evt in deferred {
+                        events.add(epoch_key(epoch), evt).map_err(|e| {
+                            e.downcast_default(
+                                ExitCode::USR_ILLEGAL_STATE,
+                                format!("failed to requeue cron event at {}", epoch),
+                            )
+                        })?;
+                    }
+                    next_cron_epoch = epoch;
+                    break;
+                }
+                if cron_events.len() >= MAX_CRON_EVENTS_PER_TICK {
+                    next_cron_epoch = epoch + 1;
+                    break;
+                }
             }
 
-            st.first_cron_epoch = rt_epoch + 1;
+            st.first_cron_epoch = next_cron_epoch;
             st.cron_event_queue = events.root().map_err(|e| {
                 e.downcast_default(ExitCode::USR_ILLEGAL_STATE, "failed to flush events")
             })?;
@@ -659,6 +749,22 @@ impl ActorCode for Actor {
                 let res = Self::current_total_power(rt)?;
                 Ok(RawBytes::serialize(res)?)
             }
+            Some(Method::MinerPower) => {
+                let res = Self::miner_power(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::NetworkQAPower) => {
+                let res = Self::network_qa_power(rt)?;
+                Ok(RawBytes::serialize(BigIntSer(&res))?)
+            }
+            Some(Method::TotalPledgeCollateral) => {
+                let res = Self::total_pledge_collateral(rt)?;
+                Ok(RawBytes::serialize(res)?)
+            }
+            Some(Method::MinerConsensusEligibility) => {
+                let res = Self::miner_consensus_eligibility(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             None => Err(actor_error!(unhandled_message; "Invalid method")),
         }
     }
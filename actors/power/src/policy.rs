@@ -11,3 +11,12 @@ pub const CONSENSUS_MINER_MIN_MINERS: i64 = 4;
 ///
 /// To support onboarding 1EiB/year, we need to allow at least 32 prove commits per epoch.
 pub const MAX_MINER_PROVE_COMMITS_PER_EPOCH: u64 = 200;
+
+/// Maximum number of deferred cron events dispatched to miners in one cron tick.
+///
+/// The cap applies per tick, not per enrolled epoch: a tick catching up on several epochs
+/// shares one allowance across them. Events beyond it are re-queued at the epoch they were
+/// enrolled for and dispatched, in order, by the following ticks. This keeps a spike of
+/// enrolled events (e.g. many pre-commit expirations falling due at the same epoch) from
+/// pushing a single cron tick over the block gas limit.
+pub const MAX_CRON_EVENTS_PER_TICK: usize = 1000;
@@ -9,6 +9,7 @@ use fvm_shared::clock::ChainEpoch;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::sector::{RegisteredPoStProof, StoragePower};
 use fvm_shared::smooth::FilterEstimate;
+use fvm_shared::ActorID;
 
 pub type SectorTermination = i64;
 
@@ -56,6 +57,31 @@ pub struct EnrollCronEventParams {
     pub payload: RawBytes,
 }
 
+#[derive(Serialize_tuple, Deserialize_tuple)]
+pub struct MinerPowerParams {
+    pub miner: ActorID,
+}
+
+impl Cbor for MinerPowerParams {}
+
+#[derive(Serialize_tuple, Deserialize_tuple, Clone, Debug, PartialEq, Eq)]
+pub struct MinerPowerReturn {
+    #[serde(with = "bigint_ser")]
+    pub raw_byte_power: StoragePower,
+    #[serde(with = "bigint_ser")]
+    pub quality_adj_power: StoragePower,
+}
+
+pub type MinerConsensusEligibilityParams = MinerPowerParams;
+
+#[derive(Serialize_tuple, Deserialize_tuple, Clone, Debug, PartialEq, Eq)]
+pub struct MinerConsensusEligibilityReturn {
+    /// Whether the miner has a power claim.
+    pub has_claim: bool,
+    /// Whether the miner's power makes it eligible to participate in consensus.
+    pub eligible: bool,
+}
+
 #[derive(Serialize_tuple, Deserialize_tuple)]
 pub struct CurrentTotalPowerReturn {
     #[serde(with = "bigint_ser")]
@@ -32,7 +32,7 @@ use fvm_shared::sector::SealVerifyInfo;
 use fvm_shared::sector::SectorNumber;
 use fvm_shared::sector::{RegisteredPoStProof, RegisteredSealProof, StoragePower};
 use fvm_shared::smooth::FilterEstimate;
-use fvm_shared::MethodNum;
+use fvm_shared::{ActorID, MethodNum};
 use lazy_static::lazy_static;
 use num_traits::Zero;
 use serde::de::DeserializeOwned;
@@ -41,8 +41,9 @@ use serde::Serialize;
 use fil_actor_power::ext::init::ExecParams;
 use fil_actor_power::ext::miner::MinerConstructorParams;
 use fil_actor_power::{
-    ext, Claim, CreateMinerParams, CreateMinerReturn, CurrentTotalPowerReturn, Method, State,
-    UpdateClaimedPowerParams,
+    ext, Claim, CreateMinerParams, CreateMinerReturn, CurrentTotalPowerReturn, Method,
+    MinerConsensusEligibilityParams, MinerConsensusEligibilityReturn, MinerPowerParams,
+    MinerPowerReturn, State, UpdateClaimedPowerParams,
 };
 use fil_actors_runtime::builtin::HAMT_BIT_WIDTH;
 use fil_actors_runtime::runtime::builtins::Type;
@@ -311,6 +312,41 @@ impl Harness {
         ret
     }
 
+    pub fn miner_power(
+        &self,
+        rt: &mut MockRuntime,
+        miner: ActorID,
+    ) -> Result<MinerPowerReturn, ActorError> {
+        rt.expect_validate_caller_any();
+        let ret = rt
+            .call::<PowerActor>(
+                Method::MinerPower as u64,
+                &RawBytes::serialize(MinerPowerParams { miner }).unwrap(),
+            )?
+            .deserialize()
+            .unwrap();
+        rt.verify();
+        Ok(ret)
+    }
+
+    pub fn miner_consensus_eligibility(
+        &self,
+        rt: &mut MockRuntime,
+        miner: ActorID,
+    ) -> MinerConsensusEligibilityReturn {
+        rt.expect_validate_caller_any();
+        let ret = rt
+            .call::<PowerActor>(
+                Method::MinerConsensusEligibility as u64,
+                &RawBytes::serialize(MinerConsensusEligibilityParams { miner }).unwrap(),
+            )
+            .unwrap()
+            .deserialize()
+            .unwrap();
+        rt.verify();
+        ret
+    }
+
     pub fn update_claimed_power(
         &self,
         rt: &mut MockRuntime,
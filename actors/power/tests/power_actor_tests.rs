@@ -8,7 +8,7 @@ use fil_actors_runtime::test_utils::{
 use fil_actors_runtime::{runtime::Policy, CALLER_TYPES_SIGNABLE, INIT_ACTOR_ADDR};
 use fvm_ipld_encoding::{BytesDe, RawBytes};
 use fvm_shared::address::Address;
-use fvm_shared::bigint::bigint_ser::BigIntSer;
+use fvm_shared::bigint::bigint_ser::{BigIntDe, BigIntSer};
 use fvm_shared::clock::ChainEpoch;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::ExitCode;
@@ -18,7 +18,8 @@ use std::ops::Neg;
 
 use fil_actor_power::{
     consensus_miner_min_power, Actor as PowerActor, CreateMinerParams, EnrollCronEventParams,
-    Method, State, UpdateClaimedPowerParams, CONSENSUS_MINER_MIN_MINERS,
+    Method, MinerConsensusEligibilityReturn, State, UpdateClaimedPowerParams,
+    CONSENSUS_MINER_MIN_MINERS, MAX_CRON_EVENTS_PER_TICK,
 };
 
 use crate::harness::*;
@@ -589,6 +590,79 @@ fn claimed_power_is_externally_available() {
     h.check_state(&rt);
 }
 
+#[test]
+fn power_and_pledge_getters_are_exported() {
+    let power_unit = &consensus_miner_min_power(
+        &Policy::default(),
+        RegisteredPoStProof::StackedDRGWindow32GiBV1,
+    )
+    .unwrap();
+
+    let (mut h, mut rt) = setup();
+
+    h.create_miner_basic(&mut rt, *OWNER, *OWNER, MINER1).unwrap();
+    h.update_claimed_power(&mut rt, MINER1, power_unit, &(power_unit * 2));
+
+    let power = h.miner_power(&mut rt, MINER1.id().unwrap()).unwrap();
+    assert_eq!(power_unit, &power.raw_byte_power);
+    assert_eq!(&(power_unit * 2), &power.quality_adj_power);
+    expect_abort(ExitCode::USR_NOT_FOUND, h.miner_power(&mut rt, MINER2.id().unwrap()));
+    rt.reset();
+
+    // Network totals match those reported by CurrentTotalPower.
+    let total = h.current_power_total(&mut rt);
+    rt.expect_validate_caller_any();
+    let qa_power: BigIntDe = rt
+        .call::<PowerActor>(Method::NetworkQAPower as u64, &RawBytes::default())
+        .unwrap()
+        .deserialize()
+        .unwrap();
+    rt.verify();
+    assert_eq!(total.quality_adj_power, qa_power.0);
+
+    rt.expect_validate_caller_any();
+    let pledge: TokenAmount = rt
+        .call::<PowerActor>(Method::TotalPledgeCollateral as u64, &RawBytes::default())
+        .unwrap()
+        .deserialize()
+        .unwrap();
+    rt.verify();
+    assert_eq!(total.pledge_collateral, pledge);
+    h.check_state(&rt);
+}
+
+#[test]
+fn miner_consensus_eligibility_is_exported() {
+    let power_unit = &consensus_miner_min_power(
+        &Policy::default(),
+        RegisteredPoStProof::StackedDRGWindow32GiBV1,
+    )
+    .unwrap();
+    let small_power_unit = &StoragePower::from(1_000_000);
+
+    let (mut h, mut rt) = setup();
+    let status = |has_claim, eligible| MinerConsensusEligibilityReturn { has_claim, eligible };
+
+    // A miner without a claim is reported as such.
+    assert_eq!(status(false, false), h.miner_consensus_eligibility(&mut rt, MINER1.id().unwrap()));
+
+    // While fewer than the minimum number of miners meet the power minimum,
+    // any miner with non-zero power is eligible.
+    h.create_miner_basic(&mut rt, *OWNER, *OWNER, MINER1).unwrap();
+    assert_eq!(status(true, false), h.miner_consensus_eligibility(&mut rt, MINER1.id().unwrap()));
+    h.update_claimed_power(&mut rt, MINER1, small_power_unit, small_power_unit);
+    assert_eq!(status(true, true), h.miner_consensus_eligibility(&mut rt, MINER1.id().unwrap()));
+
+    // Once enough miners meet the minimum, smaller miners are not eligible.
+    for miner in [MINER2, MINER3, MINER4, MINER5] {
+        h.create_miner_basic(&mut rt, *OWNER, *OWNER, miner).unwrap();
+        h.update_claimed_power(&mut rt, miner, power_unit, power_unit);
+    }
+    assert_eq!(status(true, false), h.miner_consensus_eligibility(&mut rt, MINER1.id().unwrap()));
+    assert_eq!(status(true, true), h.miner_consensus_eligibility(&mut rt, MINER2.id().unwrap()));
+    h.check_state(&rt);
+}
+
 #[test]
 fn given_no_miner_claim_update_pledge_total_should_abort() {
     let (mut h, mut rt) = setup();
@@ -764,6 +838,75 @@ mod cron_tests {
         h.check_state(&rt);
     }
 
+    #[test]
+    fn events_beyond_per_tick_limit_spill_to_next_round() {
+        let (mut h, mut rt) = setup();
+        let miner1 = Address::new_id(101);
+        h.create_miner_basic(&mut rt, OWNER, OWNER, miner1).unwrap();
+
+        rt.set_epoch(1);
+        for _ in 0..MAX_CRON_EVENTS_PER_TICK {
+            h.enroll_cron_event(&mut rt, 2, &miner1, &RawBytes::from(vec![0x01])).unwrap();
+        }
+        h.enroll_cron_event(&mut rt, 2, &miner1, &RawBytes::from(vec![0x02])).unwrap();
+
+        let expect_cron = |rt: &mut MockRuntime, payload: Vec<u8>, count: usize| {
+            let state: State = rt.get_state();
+            let params = DeferredCronEventParams {
+                event_payload: payload,
+                reward_smoothed: h.this_epoch_reward_smoothed.clone(),
+                quality_adj_power_smoothed: state.this_epoch_qa_power_smoothed,
+            };
+            for _ in 0..count {
+                rt.expect_send(
+                    miner1,
+                    ON_DEFERRED_CRON_EVENT_METHOD,
+                    RawBytes::serialize(&params).unwrap(),
+                    TokenAmount::zero(),
+                    RawBytes::default(),
+                    ExitCode::OK,
+                );
+            }
+        };
+        let expect_tick = |rt: &mut MockRuntime| {
+            rt.expect_validate_caller_addr(vec![CRON_ACTOR_ADDR]);
+            h.expect_query_network_info(rt);
+        };
+        let run_tick = |rt: &mut MockRuntime| {
+            rt.expect_send(
+                REWARD_ACTOR_ADDR,
+                UPDATE_NETWORK_KPI,
+                RawBytes::serialize(BigIntSer(&BigInt::zero())).unwrap(),
+                TokenAmount::zero(),
+                RawBytes::default(),
+                ExitCode::OK,
+            );
+            rt.set_caller(*CRON_ACTOR_CODE_ID, CRON_ACTOR_ADDR);
+            rt.expect_batch_verify_seals(Vec::new(), Ok(Vec::new()));
+            rt.call::<PowerActor>(Method::OnEpochTickEnd as u64, &RawBytes::default()).unwrap();
+            rt.verify();
+        };
+
+        // the first tick dispatches up to the limit and leaves the rest queued
+        rt.set_epoch(2);
+        expect_tick(&mut rt);
+        expect_cron(&mut rt, vec![0x01], MAX_CRON_EVENTS_PER_TICK);
+        run_tick(&mut rt);
+        let state: State = rt.get_state();
+        assert_eq!(2, state.first_cron_epoch);
+        assert_eq!(1, h.get_enrolled_cron_ticks(&rt, 2).len());
+
+        // the next tick picks up the spillover
+        rt.set_epoch(3);
+        expect_tick(&mut rt);
+        expect_cron(&mut rt, vec![0x02], 1);
+        run_tick(&mut rt);
+        let state: State = rt.get_state();
+        assert_eq!(4, state.first_cron_epoch);
+        verify_empty_map(&rt, state.cron_event_queue);
+        h.check_state(&rt);
+    }
+
     #[test]
     fn event_scheduled_in_past_called_next_round() {
         let (mut h, mut rt) = setup();
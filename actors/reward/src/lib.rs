@@ -9,13 +9,13 @@ use fil_actors_runtime::{
 
 use fvm_ipld_encoding::RawBytes;
 use fvm_shared::address::Address;
-use fvm_shared::bigint::bigint_ser::BigIntDe;
+use fvm_shared::bigint::bigint_ser::{BigIntDe, BigIntSer};
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::sector::StoragePower;
 use fvm_shared::{MethodNum, METHOD_CONSTRUCTOR, METHOD_SEND};
 use log::{error, warn};
 use num_derive::FromPrimitive;
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, Signed, Zero};
 
 pub use self::logic::*;
 pub use self::state::{Reward, State, VestingFunction};
@@ -47,6 +47,9 @@ pub enum Method {
     AwardBlockReward = 2,
     ThisEpochReward = 3,
     UpdateNetworkKPI = 4,
+    // Read-only methods exported with FRC-42 numbers.
+    ThisEpochBaselinePower = frc42_dispatch::method_hash!("ThisEpochBaselinePower"),
+    RewardForPower = frc42_dispatch::method_hash!("RewardForPower"),
 }
 
 /// Reward Actor
@@ -60,7 +63,8 @@ impl Actor {
         rt.validate_immediate_caller_is(std::iter::once(&SYSTEM_ACTOR_ADDR))?;
 
         if let Some(power) = curr_realized_power {
-            rt.create(&State::new(power))?;
+            let st = State::new(rt.policy(), power);
+            rt.create(&st)?;
             Ok(())
         } else {
             Err(actor_error!(illegal_argument, "argument should not be nil"))
@@ -186,6 +190,43 @@ impl Actor {
         })
     }
 
+    /// The baseline power for the current epoch.
+    fn this_epoch_baseline_power(rt: &mut impl Runtime) -> Result<StoragePower, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        Ok(st.this_epoch_baseline_power)
+    }
+
+    /// Estimates the reward earned per epoch by the given quality-adjusted power,
+    /// from the smoothed estimates of the epoch reward and the network's total power.
+    fn reward_for_power(
+        rt: &mut impl Runtime,
+        params: RewardForPowerParams,
+    ) -> Result<TokenAmount, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        if params.qa_power.is_negative() {
+            return Err(actor_error!(
+                illegal_argument,
+                "power {} must not be negative",
+                params.qa_power
+            ));
+        }
+        let st: State = rt.state()?;
+        let network_power: ext::power::CurrentTotalPowerReturn = rt
+            .send(
+                &STORAGE_POWER_ACTOR_ADDR,
+                ext::power::CURRENT_TOTAL_POWER_METHOD,
+                RawBytes::default(),
+                TokenAmount::zero(),
+            )?
+            .deserialize()?;
+        Ok(expected_reward_for_power(
+            &st.this_epoch_reward_smoothed,
+            &network_power.quality_adj_power_smoothed,
+            &params.qa_power,
+        ))
+    }
+
     /// Called at the end of each epoch by the power actor (in turn by its cron hook).
     /// This is only invoked for non-empty tipsets, but catches up any number of null
     /// epochs to compute the next epoch reward.
@@ -203,10 +244,10 @@ impl Actor {
             // st.Epoch == rt.CurrEpoch()
             while st.epoch < rt.curr_epoch() {
                 // Update to next epoch to process null rounds
-                st.update_to_next_epoch(&curr_realized_power);
+                st.update_to_next_epoch(rt.policy(), &curr_realized_power);
             }
 
-            st.update_to_next_epoch_with_reward(&curr_realized_power);
+            st.update_to_next_epoch_with_reward(rt.policy(), &curr_realized_power);
             st.update_smoothed_estimates(st.epoch - prev);
             Ok(())
         })?;
@@ -242,6 +283,14 @@ impl ActorCode for Actor {
                 Self::update_network_kpi(rt, param.map(|v| v.0))?;
                 Ok(RawBytes::default())
             }
+            Some(Method::ThisEpochBaselinePower) => {
+                let res = Self::this_epoch_baseline_power(rt)?;
+                Ok(RawBytes::serialize(BigIntSer(&res))?)
+            }
+            Some(Method::RewardForPower) => {
+                let res = Self::reward_for_power(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             None => Err(actor_error!(unhandled_message, "Invalid method")),
         }
     }
@@ -1,36 +1,19 @@
 // Copyright 2019-2022 ChainSafe Systems
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use std::str::FromStr;
-
+use fil_actors_runtime::runtime::Policy;
 use fvm_shared::bigint::{BigInt, Integer};
 use fvm_shared::clock::ChainEpoch;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::math::PRECISION;
 use fvm_shared::sector::StoragePower;
+use fvm_shared::smooth::{self, FilterEstimate};
 use lazy_static::lazy_static;
+use num_traits::Zero;
 
 use super::expneg::expneg;
 
 lazy_static! {
-    /// Floor(e^(ln[1 + 200%] / epochsInYear) * 2^128
-    /// Q.128 formatted number such that f(epoch) = baseExponent^epoch grows 200% in one
-    /// year of epochs
-    /// Calculation here: https://www.wolframalpha.com/input/?i=IntegerPart%5BExp%5BLog%5B1%2B200%25%5D%2F%28%28365+days%29%2F%2830+seconds%29%29%5D*2%5E128%5D
-    pub static ref BASELINE_EXPONENT: StoragePower =
-        StoragePower::from_str("340282591298641078465964189926313473653").unwrap();
-
-    // 2.5057116798121726 EiB
-    pub static ref BASELINE_INITIAL_VALUE: StoragePower = StoragePower::from(2_888_888_880_000_000_000u128);
-
-    /// 1EiB
-    pub static ref INIT_BASELINE_POWER: StoragePower =
-    ((BASELINE_INITIAL_VALUE.clone() << (2*PRECISION)) / &*BASELINE_EXPONENT) >> PRECISION;
-
-    /// 330M for mainnet
-    pub(super) static ref SIMPLE_TOTAL: TokenAmount = TokenAmount::from_whole(330_000_000);
-    /// 770M for mainnet
-    pub(super) static ref BASELINE_TOTAL: TokenAmount = TokenAmount::from_whole(770_000_000);
     /// expLamSubOne = e^lambda - 1
     /// for Q.128: int(expLamSubOne * 2^128)
     static ref EXP_LAM_SUB_ONE: BigInt = BigInt::from(37396273494747879394193016954629u128);
@@ -40,10 +23,38 @@ lazy_static! {
 
 }
 
+/// Estimates the reward earned in one epoch by the given quality-adjusted power, as its share of
+/// the estimated epoch reward against the estimated network power.
+/// This matches the miner actor's expected reward projection over a single epoch.
+pub fn expected_reward_for_power(
+    reward_estimate: &FilterEstimate,
+    network_qa_power_estimate: &FilterEstimate,
+    qa_power: &StoragePower,
+) -> TokenAmount {
+    if network_qa_power_estimate.estimate().is_zero() {
+        return TokenAmount::from_atto(reward_estimate.estimate());
+    }
+    let reward_per_power =
+        smooth::extrapolated_cum_sum_of_ratio(1, 0, reward_estimate, network_qa_power_estimate);
+    let reward = (qa_power * reward_per_power) >> PRECISION;
+    TokenAmount::from_atto(std::cmp::max(reward, BigInt::zero()))
+}
+
 /// Compute BaselinePower(t) from BaselinePower(t-1) with an additional multiplication
 /// of the base exponent.
-pub(crate) fn baseline_power_from_prev(prev_power: &StoragePower) -> StoragePower {
-    (prev_power * &*BASELINE_EXPONENT) >> PRECISION
+pub(crate) fn baseline_power_from_prev(
+    prev_power: &StoragePower,
+    baseline_exponent: &StoragePower,
+) -> StoragePower {
+    (prev_power * baseline_exponent) >> PRECISION
+}
+
+/// Baseline power target for the epoch before the first, such that the first epoch's target
+/// is the policy's initial baseline value.
+pub fn init_baseline_power(policy: &Policy) -> StoragePower {
+    ((policy.reward_baseline_initial_value.clone() << (2 * PRECISION))
+        / &policy.reward_baseline_exponent)
+        >> PRECISION
 }
 
 /// Computes RewardTheta which is is precise fractional value of effectiveNetworkTime.
@@ -166,6 +177,7 @@ mod tests {
     // Converted from: https://github.com/filecoin-project/specs-actors/blob/d56b240af24517443ce1f8abfbdab7cb22d331f1/actors/builtin/reward/reward_logic_test.go#L43
     #[test]
     fn test_baseline_reward() {
+        let policy = Policy::default();
         let step = BigInt::from(5000_i64).shl(u128::BITS) - BigInt::from(77_777_777_777_i64); // offset from full integers
         let delta = BigInt::from(1_i64).shl(u128::BITS) - BigInt::from(33_333_333_333_i64); // offset from full integers
 
@@ -177,8 +189,8 @@ mod tests {
             0,
             BigInt::from(0i64),
             BigInt::from(0i64),
-            &SIMPLE_TOTAL,
-            &BASELINE_TOTAL,
+            &policy.reward_simple_total,
+            &policy.reward_baseline_total,
         );
 
         for _ in 0..512 {
@@ -186,8 +198,8 @@ mod tests {
                 0,
                 prev_theta.clone(),
                 theta.clone(),
-                &SIMPLE_TOTAL,
-                &BASELINE_TOTAL,
+                &policy.reward_simple_total,
+                &policy.reward_baseline_total,
             );
             reward -= &simple;
 
@@ -216,6 +228,7 @@ mod tests {
     // Converted from: https://github.com/filecoin-project/specs-actors/blob/d56b240af24517443ce1f8abfbdab7cb22d331f1/actors/builtin/reward/reward_logic_test.go#L70
     #[test]
     fn test_simple_reward() {
+        let policy = Policy::default();
         let mut b = String::from("x, y\n");
         for i in 0..512 {
             let x: i64 = i * 5000;
@@ -223,8 +236,8 @@ mod tests {
                 x,
                 BigInt::from(0i64),
                 BigInt::from(0i64),
-                &SIMPLE_TOTAL,
-                &BASELINE_TOTAL,
+                &policy.reward_simple_total,
+                &policy.reward_baseline_total,
             );
 
             let x_str = &x.to_string();
@@ -246,13 +259,14 @@ mod tests {
     // Converted from: https://github.com/filecoin-project/specs-actors/blob/d56b240af24517443ce1f8abfbdab7cb22d331f1/actors/builtin/reward/reward_logic_test.go#L82
     #[test]
     fn test_baseline_reward_growth() {
-        fn baseline_in_years(start: StoragePower, x: ChainEpoch) -> StoragePower {
+        let policy = Policy::default();
+        let baseline_in_years = |start: StoragePower, x: ChainEpoch| -> StoragePower {
             let mut baseline = start;
             for _ in 0..(x * EPOCHS_IN_YEAR) {
-                baseline = baseline_power_from_prev(&baseline);
+                baseline = baseline_power_from_prev(&baseline, &policy.reward_baseline_exponent);
             }
             baseline
-        }
+        };
 
         struct GrowthTestCase {
             start_val: StoragePower,
@@ -269,7 +283,10 @@ mod tests {
             // PiB
             GrowthTestCase { start_val: StoragePower::from(1i64 << 50), err_bound: 1e-8 },
             // EiB
-            GrowthTestCase { start_val: BASELINE_INITIAL_VALUE.clone(), err_bound: 1e-8 },
+            GrowthTestCase {
+                start_val: policy.reward_baseline_initial_value.clone(),
+                err_bound: 1e-8,
+            },
             // ZiB
             GrowthTestCase { start_val: StoragePower::from(1u128 << 70), err_bound: 1e-8 },
             // non power of 2 ~ 1 EiB
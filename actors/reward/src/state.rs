@@ -1,6 +1,7 @@
 // Copyright 2019-2022 ChainSafe Systems
 // SPDX-License-Identifier: Apache-2.0, MIT
 
+use fil_actors_runtime::runtime::Policy;
 use fvm_ipld_encoding::repr::*;
 use fvm_ipld_encoding::tuple::*;
 use fvm_ipld_encoding::Cbor;
@@ -72,51 +73,64 @@ pub struct State {
 }
 
 impl State {
-    pub fn new(curr_realized_power: StoragePower) -> Self {
+    pub fn new(policy: &Policy, curr_realized_power: StoragePower) -> Self {
         let mut st = Self {
-            effective_baseline_power: BASELINE_INITIAL_VALUE.clone(),
-            this_epoch_baseline_power: INIT_BASELINE_POWER.clone(),
+            effective_baseline_power: policy.reward_baseline_initial_value.clone(),
+            this_epoch_baseline_power: init_baseline_power(policy),
             epoch: EPOCH_UNDEFINED,
             this_epoch_reward_smoothed: FilterEstimate::new(
                 INITIAL_REWARD_POSITION_ESTIMATE.atto().clone(),
                 INITIAL_REWARD_VELOCITY_ESTIMATE.atto().clone(),
             ),
-            simple_total: SIMPLE_TOTAL.clone(),
-            baseline_total: BASELINE_TOTAL.clone(),
+            simple_total: policy.reward_simple_total.clone(),
+            baseline_total: policy.reward_baseline_total.clone(),
             ..Default::default()
         };
-        st.update_to_next_epoch_with_reward(&curr_realized_power);
+        st.update_to_next_epoch_with_reward(policy, &curr_realized_power);
 
         st
     }
 
     /// Takes in current realized power and updates internal state
     /// Used for update of internal state during null rounds
-    pub(super) fn update_to_next_epoch(&mut self, curr_realized_power: &StoragePower) {
+    pub(super) fn update_to_next_epoch(
+        &mut self,
+        policy: &Policy,
+        curr_realized_power: &StoragePower,
+    ) {
         self.epoch += 1;
-        self.this_epoch_baseline_power = baseline_power_from_prev(&self.this_epoch_baseline_power);
+        self.this_epoch_baseline_power = baseline_power_from_prev(
+            &self.this_epoch_baseline_power,
+            &policy.reward_baseline_exponent,
+        );
         let capped_realized_power =
             std::cmp::min(&self.this_epoch_baseline_power, curr_realized_power);
         self.cumsum_realized += capped_realized_power;
 
         while self.cumsum_realized > self.cumsum_baseline {
             self.effective_network_time += 1;
-            self.effective_baseline_power =
-                baseline_power_from_prev(&self.effective_baseline_power);
+            self.effective_baseline_power = baseline_power_from_prev(
+                &self.effective_baseline_power,
+                &policy.reward_baseline_exponent,
+            );
             self.cumsum_baseline += &self.effective_baseline_power;
         }
     }
 
     /// Takes in a current realized power for a reward epoch and computes
     /// and updates reward state to track reward for the next epoch
-    pub(super) fn update_to_next_epoch_with_reward(&mut self, curr_realized_power: &StoragePower) {
+    pub(super) fn update_to_next_epoch_with_reward(
+        &mut self,
+        policy: &Policy,
+        curr_realized_power: &StoragePower,
+    ) {
         let prev_reward_theta = compute_r_theta(
             self.effective_network_time,
             &self.effective_baseline_power,
             &self.cumsum_realized,
             &self.cumsum_baseline,
         );
-        self.update_to_next_epoch(curr_realized_power);
+        self.update_to_next_epoch(policy, curr_realized_power);
         let curr_reward_theta = compute_r_theta(
             self.effective_network_time,
             &self.effective_baseline_power,
@@ -1,4 +1,5 @@
 use crate::{baseline_power_from_prev, State};
+use fil_actors_runtime::runtime::Policy;
 use fil_actors_runtime::MessageAccumulator;
 use fvm_shared::{clock::ChainEpoch, econ::TokenAmount};
 use num_traits::Signed;
@@ -7,13 +8,14 @@ use num_traits::Signed;
 pub struct StateSummary {}
 
 pub fn check_state_invariants(
+    policy: &Policy,
     state: &State,
     prior_epoch: ChainEpoch,
     balance: &TokenAmount,
 ) -> (StateSummary, MessageAccumulator) {
     let acc = MessageAccumulator::default();
 
-    let storage_mining_allocation_check = TokenAmount::from_whole(1_100_000_000);
+    let storage_mining_allocation_check = &state.simple_total + &state.baseline_total;
 
     // Can't assert equality because anyone can send funds to reward actor (and already have on mainnet)
     acc.require(
@@ -55,7 +57,10 @@ pub fn check_state_invariants(
     // Theoretically we should compare effective_baseline_power <= this_epoch_baseline_power but
     // because of rounding issues explained and tracked in https://github.com/filecoin-project/builtin-actors/issues/459
     // we settled on this workaround.
-    let next_epoch_baseline_power = baseline_power_from_prev(&state.this_epoch_baseline_power);
+    let next_epoch_baseline_power = baseline_power_from_prev(
+        &state.this_epoch_baseline_power,
+        &policy.reward_baseline_exponent,
+    );
     acc.require(
         state.effective_baseline_power <= next_epoch_baseline_power,
         format!(
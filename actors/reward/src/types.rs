@@ -3,7 +3,9 @@
 
 use fvm_ipld_encoding::tuple::*;
 use fvm_shared::address::Address;
+use fvm_shared::bigint::bigint_ser;
 use fvm_shared::econ::TokenAmount;
+use fvm_shared::sector::StoragePower;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
 pub struct AwardBlockRewardParams {
@@ -13,4 +15,10 @@ pub struct AwardBlockRewardParams {
     pub win_count: i64,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct RewardForPowerParams {
+    #[serde(with = "bigint_ser")]
+    pub qa_power: StoragePower,
+}
+
 pub use fvm_shared::reward::ThisEpochRewardReturn;
@@ -3,8 +3,9 @@
 
 use fil_actor_reward::{
     ext, Actor as RewardActor, AwardBlockRewardParams, Method, State, ThisEpochRewardReturn,
-    BASELINE_INITIAL_VALUE, PENALTY_MULTIPLIER,
+    PENALTY_MULTIPLIER,
 };
+use fil_actors_runtime::runtime::Policy;
 use fil_actors_runtime::test_utils::*;
 use fil_actors_runtime::EXPECTED_LEADERS_PER_EPOCH;
 use fil_actors_runtime::{
@@ -43,8 +44,26 @@ mod construction_tests {
         assert_eq!(ChainEpoch::from(0), state.epoch);
         assert_eq!(start_realized_power, state.cumsum_realized);
         assert_eq!(*EPOCH_ZERO_REWARD, state.this_epoch_reward);
-        assert_eq!(&*BASELINE_INITIAL_VALUE - 1, state.this_epoch_baseline_power);
-        assert_eq!(&*BASELINE_INITIAL_VALUE, &state.effective_baseline_power);
+        let baseline_initial_value = Policy::default().reward_baseline_initial_value;
+        assert_eq!(&baseline_initial_value - 1, state.this_epoch_baseline_power);
+        assert_eq!(baseline_initial_value, state.effective_baseline_power);
+    }
+
+    #[test]
+    fn construct_with_reward_policy() {
+        let policy = Policy {
+            reward_baseline_initial_value: StoragePower::from(1_i64 << 30),
+            reward_simple_total: TokenAmount::from_whole(1_000),
+            reward_baseline_total: TokenAmount::from_whole(2_000),
+            ..Default::default()
+        };
+        let rt = construct_with_policy(policy.clone(), &StoragePower::zero());
+
+        let state: State = rt.get_state();
+        assert_eq!(policy.reward_baseline_initial_value, state.effective_baseline_power);
+        assert_eq!(policy.reward_simple_total, state.simple_total);
+        assert_eq!(policy.reward_baseline_total, state.baseline_total);
+        assert!(state.this_epoch_reward < *EPOCH_ZERO_REWARD);
     }
 
     #[test]
@@ -60,7 +79,7 @@ mod construction_tests {
 
     #[test]
     fn construct_with_more_power_than_baseline() {
-        let mut start_realized_power = BASELINE_INITIAL_VALUE.clone();
+        let mut start_realized_power = Policy::default().reward_baseline_initial_value;
         let rt = construct_and_verify(&start_realized_power);
 
         let state: State = rt.get_state();
@@ -322,6 +341,102 @@ mod test_this_epoch_reward {
     }
 }
 
+mod test_exported_getters {
+    use fil_actor_reward::{expected_reward_for_power, RewardForPowerParams};
+    use fvm_shared::bigint::bigint_ser::BigIntDe;
+    use fvm_shared::bigint::BigInt;
+    use fvm_shared::smooth::FilterEstimate;
+    use num::Zero;
+
+    use super::*;
+
+    #[test]
+    fn fetch_baseline_power() {
+        let mut rt = construct_and_verify(&StoragePower::from(1));
+        let state: State = rt.get_state();
+
+        rt.expect_validate_caller_any();
+        let ret: BigIntDe = rt
+            .call::<RewardActor>(Method::ThisEpochBaselinePower as u64, &RawBytes::default())
+            .unwrap()
+            .deserialize()
+            .unwrap();
+        rt.verify();
+        assert_eq!(state.this_epoch_baseline_power, ret.0);
+    }
+
+    #[test]
+    fn estimate_reward_for_power() {
+        let network_power = StoragePower::from_i128(1 << 50).unwrap();
+        let mut rt = construct_and_verify(&network_power);
+        let state: State = rt.get_state();
+        let network_estimate = FilterEstimate::new(network_power.clone(), BigInt::zero());
+
+        let full = reward_for_power(&mut rt, &network_power, &network_estimate).unwrap();
+        assert_eq!(
+            expected_reward_for_power(
+                &state.this_epoch_reward_smoothed,
+                &network_estimate,
+                &network_power
+            ),
+            full
+        );
+        assert!(full.is_positive());
+
+        let half = reward_for_power(&mut rt, &(&network_power / 2), &network_estimate).unwrap();
+        assert!(half < full);
+        assert!(reward_for_power(&mut rt, &StoragePower::zero(), &network_estimate)
+            .unwrap()
+            .is_zero());
+    }
+
+    #[test]
+    fn rejects_negative_power() {
+        let mut rt = construct_and_verify(&StoragePower::from(1));
+        rt.expect_validate_caller_any();
+        let params = RewardForPowerParams { qa_power: StoragePower::from(-1) };
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            rt.call::<RewardActor>(
+                Method::RewardForPower as u64,
+                &RawBytes::serialize(params).unwrap(),
+            ),
+        );
+    }
+
+    fn reward_for_power(
+        rt: &mut MockRuntime,
+        qa_power: &StoragePower,
+        network_estimate: &FilterEstimate,
+    ) -> Result<TokenAmount, ActorError> {
+        rt.expect_validate_caller_any();
+        let network = ext::power::CurrentTotalPowerReturn {
+            raw_byte_power: network_estimate.position.clone(),
+            quality_adj_power: network_estimate.position.clone(),
+            pledge_collateral: TokenAmount::zero(),
+            quality_adj_power_smoothed: network_estimate.clone(),
+        };
+        rt.expect_send(
+            STORAGE_POWER_ACTOR_ADDR,
+            ext::power::CURRENT_TOTAL_POWER_METHOD,
+            RawBytes::default(),
+            TokenAmount::zero(),
+            RawBytes::serialize(network).unwrap(),
+            ExitCode::OK,
+        );
+        let params = RewardForPowerParams { qa_power: qa_power.clone() };
+        let ret = rt
+            .call::<RewardActor>(
+                Method::RewardForPower as u64,
+                &RawBytes::serialize(params).unwrap(),
+            )?
+            .deserialize()
+            .unwrap();
+        rt.verify();
+        Ok(ret)
+    }
+}
+
 #[test]
 fn test_successive_kpi_updates() {
     let power = StoragePower::from_i128(1 << 50).unwrap();
@@ -334,10 +449,15 @@ fn test_successive_kpi_updates() {
 }
 
 fn construct_and_verify(curr_power: &StoragePower) -> MockRuntime {
+    construct_with_policy(Policy::default(), curr_power)
+}
+
+fn construct_with_policy(policy: Policy, curr_power: &StoragePower) -> MockRuntime {
     let mut rt = MockRuntime {
         receiver: REWARD_ACTOR_ADDR,
         caller: SYSTEM_ACTOR_ADDR,
         caller_type: *SYSTEM_ACTOR_CODE_ID,
+        policy,
         ..Default::default()
     };
     rt.expect_validate_caller_addr(vec![SYSTEM_ACTOR_ADDR]);
@@ -60,7 +60,11 @@ pub enum Method {
     GetClaims = 10,
     ExtendClaimTerms = 11,
     RemoveExpiredClaims = 12,
+    IncreaseVerifierAllowance = 13,
+    DecreaseVerifierAllowance = 14,
+    UpdateVerifiers = 15,
     UniversalReceiverHook = frc42_dispatch::method_hash!("Receive"),
+    GetAllocation = frc42_dispatch::method_hash!("GetAllocation"),
 }
 
 pub struct Actor;
@@ -139,6 +143,186 @@ impl Actor {
         })
     }
 
+    pub fn increase_verifier_allowance(
+        rt: &mut impl Runtime,
+        params: ChangeVerifierAllowanceParams,
+    ) -> Result<(), ActorError> {
+        Self::change_verifier_allowance(rt, params, true)
+    }
+
+    pub fn decrease_verifier_allowance(
+        rt: &mut impl Runtime,
+        params: ChangeVerifierAllowanceParams,
+    ) -> Result<(), ActorError> {
+        Self::change_verifier_allowance(rt, params, false)
+    }
+
+    // Adjusts an existing verifier's allowance in place, without removing the verifier.
+    // The new allowance must be at least the minimum with which a verifier may be added.
+    fn change_verifier_allowance(
+        rt: &mut impl Runtime,
+        params: ChangeVerifierAllowanceParams,
+        increase: bool,
+    ) -> Result<(), ActorError> {
+        let st: State = rt.state()?;
+        rt.validate_immediate_caller_is(std::iter::once(&st.root_key))?;
+
+        if !params.amount.is_positive() {
+            return Err(actor_error!(
+                illegal_argument,
+                "allowance change {} must be positive",
+                params.amount
+            ));
+        }
+
+        let verifier = rt.resolve_address(&params.address).ok_or_else(|| {
+            actor_error!(not_found, "failed to resolve verifier address {}", params.address)
+        })?;
+        let verifier = Address::new_id(verifier);
+
+        rt.transaction(|st: &mut State, rt| {
+            let cap = st
+                .get_verifier_cap(rt.store(), &verifier)?
+                .ok_or_else(|| actor_error!(not_found, "{} is not a verifier", verifier))?;
+            let new_cap = if increase {
+                cap + &params.amount
+            } else {
+                if cap < params.amount {
+                    return Err(actor_error!(
+                        illegal_argument,
+                        "cannot decrease allowance {} of verifier {} by {}",
+                        cap,
+                        verifier,
+                        params.amount
+                    ));
+                }
+                cap - &params.amount
+            };
+            if new_cap < rt.policy().minimum_verified_allocation_size {
+                return Err(actor_error!(
+                    illegal_argument,
+                    "allowance {} below minimum deal size for verifier {}",
+                    new_cap,
+                    verifier
+                ));
+            }
+            st.put_verifier(rt.store(), &verifier, &new_cap)
+                .context("failed to update verifier allowance")
+        })
+    }
+
+    /// Applies a batch of verifier additions, removals and allowance changes,
+    /// so that routine changes don't each need a separate root key proposal.
+    pub fn update_verifiers(
+        rt: &mut impl Runtime,
+        params: UpdateVerifiersParams,
+    ) -> Result<UpdateVerifiersReturn, ActorError> {
+        let st: State = rt.state()?;
+        rt.validate_immediate_caller_is(std::iter::once(&st.root_key))?;
+        if params.updates.is_empty() {
+            return Err(actor_error!(illegal_argument, "update verifiers called with no updates"));
+        }
+
+        // Checks which may send messages are made before the state transaction.
+        let checked: Vec<Result<(Address, DataCap), ExitCode>> = params
+            .updates
+            .into_iter()
+            .map(|update| match Self::check_verifier_update(rt, &st.root_key, &update) {
+                Ok(verifier) => Ok((verifier, update.allowance)),
+                Err(e) => {
+                    info!("invalid update for verifier {}: {}", update.address, e);
+                    Err(e.exit_code())
+                }
+            })
+            .collect();
+
+        let mut ret_gen = BatchReturnGen::new(checked.len());
+        rt.transaction(|st: &mut State, rt| {
+            let mut verifiers = st.load_verifiers(rt.store())?;
+            for item in checked {
+                let (verifier, allowance) = match item {
+                    Ok(item) => item,
+                    Err(code) => {
+                        ret_gen.add_fail(code);
+                        continue;
+                    }
+                };
+                if allowance.is_zero() {
+                    let removed = verifiers
+                        .delete(&verifier.to_bytes())
+                        .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to remove verifier")?;
+                    if removed.is_none() {
+                        info!("{} is not a verifier", verifier);
+                        ret_gen.add_fail(ExitCode::USR_NOT_FOUND);
+                        continue;
+                    }
+                } else {
+                    verifiers
+                        .set(verifier.to_bytes().into(), BigIntDe(allowance))
+                        .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to set verifier")?;
+                }
+                ret_gen.add_success();
+            }
+            st.verifiers = verifiers
+                .flush()
+                .context_code(ExitCode::USR_ILLEGAL_STATE, "failed to flush verifiers")?;
+            Ok(())
+        })
+        .context("state transaction failed")?;
+
+        let batch_info = ret_gen.gen();
+        if params.all_or_nothing && !batch_info.all_ok() {
+            return Err(actor_error!(
+                illegal_argument,
+                "all or nothing call contained failures: {}",
+                batch_info.to_string()
+            ));
+        }
+        Ok(batch_info)
+    }
+
+    // Validates a single verifier update, returning the verifier's ID address.
+    // Removals don't create the verifier's account, nor require it to hold no datacap.
+    fn check_verifier_update(
+        rt: &mut impl Runtime,
+        root_key: &Address,
+        update: &VerifierUpdate,
+    ) -> Result<Address, ActorError> {
+        let remove = update.allowance.is_zero();
+        if !remove && update.allowance < rt.policy().minimum_verified_allocation_size {
+            return Err(actor_error!(
+                illegal_argument,
+                "allowance {} below minimum deal size for verifier {}",
+                update.allowance,
+                update.address
+            ));
+        }
+
+        let verifier = if remove {
+            rt.resolve_address(&update.address).ok_or_else(|| {
+                actor_error!(not_found, "failed to resolve verifier address {}", update.address)
+            })?
+        } else {
+            resolve_to_actor_id(rt, &update.address)?
+        };
+        let verifier = Address::new_id(verifier);
+        if remove {
+            return Ok(verifier);
+        }
+
+        if &verifier == root_key {
+            return Err(actor_error!(illegal_argument, "Rootkey cannot be added as verifier"));
+        }
+        if balance_of(rt, &verifier)?.is_positive() {
+            return Err(actor_error!(
+                illegal_argument,
+                "verified client {} cannot become a verifier",
+                verifier
+            ));
+        }
+        Ok(verifier)
+    }
+
     pub fn add_verified_client(
         rt: &mut impl Runtime,
         params: AddVerifierClientParams,
@@ -486,6 +670,27 @@ impl Actor {
         Ok(GetClaimsReturn { batch_info: batch_gen.gen(), claims })
     }
 
+    /// Returns a single allocation made by a client.
+    /// Aborts with USR_NOT_FOUND if the client has no allocation with the given ID.
+    pub fn get_allocation(
+        rt: &mut impl Runtime,
+        params: GetAllocationParams,
+    ) -> Result<Allocation, ActorError> {
+        rt.validate_immediate_caller_accept_any()?;
+        let st: State = rt.state()?;
+        let mut allocs = st.load_allocs(rt.store())?;
+        state::get_allocation(&mut allocs, params.client, params.allocation_id)?
+            .cloned()
+            .ok_or_else(|| {
+                actor_error!(
+                    not_found,
+                    "no allocation {} for client {}",
+                    params.allocation_id,
+                    params.client
+                )
+            })
+    }
+
     /// Extends the maximum term of some claims up to the largest value they could have been
     /// originally allocated.
     /// Callable only by the claims' client.
@@ -1080,6 +1285,18 @@ impl ActorCode for Actor {
                 Self::remove_verifier(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::default())
             }
+            Some(Method::IncreaseVerifierAllowance) => {
+                Self::increase_verifier_allowance(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::default())
+            }
+            Some(Method::DecreaseVerifierAllowance) => {
+                Self::decrease_verifier_allowance(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::default())
+            }
+            Some(Method::UpdateVerifiers) => {
+                let res = Self::update_verifiers(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             Some(Method::AddVerifiedClient) => {
                 Self::add_verified_client(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::default())
@@ -1109,6 +1326,10 @@ impl ActorCode for Actor {
                 let res = Self::remove_expired_claims(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::serialize(res)?)
             }
+            Some(Method::GetAllocation) => {
+                let res = Self::get_allocation(rt, cbor::deserialize_params(params)?)?;
+                Ok(RawBytes::serialize(res)?)
+            }
             Some(Method::UniversalReceiverHook) => {
                 let res = Self::universal_receiver_hook(rt, cbor::deserialize_params(params)?)?;
                 Ok(RawBytes::serialize(res)?)
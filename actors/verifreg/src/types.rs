@@ -32,6 +32,35 @@ pub type AddVerifierParams = VerifierParams;
 
 pub type AddVerifierClientParams = VerifierParams;
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct ChangeVerifierAllowanceParams {
+    pub address: Address,
+    #[serde(with = "bigint_ser")]
+    pub amount: DataCap,
+}
+
+impl Cbor for ChangeVerifierAllowanceParams {}
+
+/// A change to a single verifier.
+/// A zero allowance removes the verifier, while any other allowance adds the verifier
+/// or replaces its current allowance.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct VerifierUpdate {
+    pub address: Address,
+    #[serde(with = "bigint_ser")]
+    pub allowance: DataCap,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct UpdateVerifiersParams {
+    pub updates: Vec<VerifierUpdate>,
+    pub all_or_nothing: bool,
+}
+
+impl Cbor for UpdateVerifiersParams {}
+
+pub type UpdateVerifiersReturn = BatchReturn;
+
 /// DataCap is an integer number of bytes.
 /// We can introduce policy changes and replace this in the future.
 pub type DataCap = StoragePower;
@@ -207,6 +236,13 @@ pub struct AllocationsResponse {
 }
 impl Cbor for AllocationsResponse {}
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
+pub struct GetAllocationParams {
+    pub client: ActorID,
+    pub allocation_id: AllocationID,
+}
+impl Cbor for GetAllocationParams {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize_tuple, Deserialize_tuple)]
 pub struct GetClaimsParams {
     pub provider: ActorID,
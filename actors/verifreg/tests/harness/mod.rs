@@ -15,11 +15,12 @@ use num_traits::{ToPrimitive, Zero};
 use fil_actor_verifreg::testing::check_state_invariants;
 use fil_actor_verifreg::{
     ext, Actor as VerifregActor, AddVerifierClientParams, AddVerifierParams, Allocation,
-    AllocationID, AllocationRequest, AllocationRequests, AllocationsResponse, Claim,
-    ClaimAllocationsParams, ClaimAllocationsReturn, ClaimExtensionRequest, ClaimID, DataCap,
-    ExtendClaimTermsParams, ExtendClaimTermsReturn, GetClaimsParams, GetClaimsReturn, Method,
-    RemoveExpiredAllocationsParams, RemoveExpiredAllocationsReturn, RemoveExpiredClaimsParams,
-    RemoveExpiredClaimsReturn, SectorAllocationClaim, State,
+    AllocationID, AllocationRequest, AllocationRequests, AllocationsResponse,
+    ChangeVerifierAllowanceParams, Claim, ClaimAllocationsParams, ClaimAllocationsReturn,
+    ClaimExtensionRequest, ClaimID, DataCap, ExtendClaimTermsParams, ExtendClaimTermsReturn,
+    GetAllocationParams, GetClaimsParams, GetClaimsReturn, Method, RemoveExpiredAllocationsParams,
+    RemoveExpiredAllocationsReturn, RemoveExpiredClaimsParams, RemoveExpiredClaimsReturn,
+    SectorAllocationClaim, State, UpdateVerifiersParams, VerifierUpdate,
 };
 use fil_actors_runtime::cbor::serialize;
 use fil_actors_runtime::runtime::builtins::Type;
@@ -138,6 +139,53 @@ impl Harness {
         Ok(())
     }
 
+    pub fn change_verifier_allowance(
+        &self,
+        rt: &mut MockRuntime,
+        method: Method,
+        verifier: &Address,
+        amount: &DataCap,
+    ) -> Result<(), ActorError> {
+        rt.expect_validate_caller_addr(vec![self.root]);
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, self.root);
+        let params = ChangeVerifierAllowanceParams { address: *verifier, amount: amount.clone() };
+        let ret =
+            rt.call::<VerifregActor>(method as MethodNum, &RawBytes::serialize(params).unwrap())?;
+        assert_eq!(RawBytes::default(), ret);
+        rt.verify();
+        Ok(())
+    }
+
+    // Applies a batch of verifier updates.
+    // `balances` are the mocked data cap balances queried for each verifier being added, in order.
+    pub fn update_verifiers(
+        &self,
+        rt: &mut MockRuntime,
+        updates: Vec<VerifierUpdate>,
+        balances: &[(Address, DataCap)],
+        all_or_nothing: bool,
+    ) -> Result<BatchReturn, ActorError> {
+        rt.expect_validate_caller_addr(vec![self.root]);
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, self.root);
+        for (verifier, cap) in balances {
+            rt.expect_send(
+                DATACAP_TOKEN_ACTOR_ADDR,
+                ext::datacap::Method::BalanceOf as MethodNum,
+                RawBytes::serialize(verifier).unwrap(),
+                TokenAmount::zero(),
+                serialize(&BigIntSer(&(cap * TOKEN_PRECISION)), "").unwrap(),
+                ExitCode::OK,
+            );
+        }
+        let params = UpdateVerifiersParams { updates, all_or_nothing };
+        let ret = rt.call::<VerifregActor>(
+            Method::UpdateVerifiers as MethodNum,
+            &RawBytes::serialize(params).unwrap(),
+        )?;
+        rt.verify();
+        Ok(ret.deserialize().unwrap())
+    }
+
     pub fn assert_verifier_allowance(
         &self,
         rt: &MockRuntime,
@@ -82,9 +82,13 @@ mod verifiers {
     use fvm_shared::error::ExitCode;
     use fvm_shared::{MethodNum, METHOD_SEND};
 
-    use fil_actor_verifreg::{Actor as VerifregActor, AddVerifierParams, DataCap, Method};
+    use fil_actor_verifreg::{
+        Actor as VerifregActor, AddVerifierParams, ChangeVerifierAllowanceParams, DataCap, Method,
+        UpdateVerifiersParams, VerifierUpdate,
+    };
     use fil_actors_runtime::test_utils::*;
     use harness::*;
+    use num_traits::Zero;
     use util::*;
 
     use crate::*;
@@ -235,6 +239,216 @@ mod verifiers {
         h.remove_verifier(&mut rt, &VERIFIER).unwrap();
         h.check_state(&rt);
     }
+
+    #[test]
+    fn change_verifier_allowance_in_place() {
+        let (h, mut rt) = new_harness();
+        let allowance = verifier_allowance(&rt);
+        h.add_verifier(&mut rt, &VERIFIER, &allowance).unwrap();
+
+        let delta = DataCap::from(10);
+        h.change_verifier_allowance(&mut rt, Method::IncreaseVerifierAllowance, &VERIFIER, &delta)
+            .unwrap();
+        h.assert_verifier_allowance(&rt, &VERIFIER, &(&allowance + &delta));
+
+        // Decreasing to the minimum allowance leaves the verifier in place.
+        let minimum = rt.policy.minimum_verified_allocation_size.clone();
+        h.change_verifier_allowance(
+            &mut rt,
+            Method::DecreaseVerifierAllowance,
+            &VERIFIER,
+            &(&allowance + &delta - &minimum),
+        )
+        .unwrap();
+        h.assert_verifier_allowance(&rt, &VERIFIER, &minimum);
+        h.check_state(&rt);
+    }
+
+    #[test]
+    fn change_verifier_allowance_requires_root() {
+        let (h, mut rt) = new_harness();
+        let allowance = verifier_allowance(&rt);
+        h.add_verifier(&mut rt, &VERIFIER, &allowance).unwrap();
+
+        let caller = Address::new_id(501);
+        rt.expect_validate_caller_addr(vec![h.root]);
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, caller);
+        let params = ChangeVerifierAllowanceParams { address: *VERIFIER, amount: DataCap::from(1) };
+        expect_abort(
+            ExitCode::USR_FORBIDDEN,
+            rt.call::<VerifregActor>(
+                Method::IncreaseVerifierAllowance as MethodNum,
+                &RawBytes::serialize(params).unwrap(),
+            ),
+        );
+        h.check_state(&rt);
+    }
+
+    #[test]
+    fn change_verifier_allowance_rejects_invalid_changes() {
+        let (h, mut rt) = new_harness();
+        let allowance = verifier_allowance(&rt);
+
+        // Not a verifier.
+        expect_abort(
+            ExitCode::USR_NOT_FOUND,
+            h.change_verifier_allowance(
+                &mut rt,
+                Method::IncreaseVerifierAllowance,
+                &VERIFIER,
+                &DataCap::from(1),
+            ),
+        );
+        rt.reset();
+
+        h.add_verifier(&mut rt, &VERIFIER, &allowance).unwrap();
+
+        // Non-positive change.
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            h.change_verifier_allowance(
+                &mut rt,
+                Method::IncreaseVerifierAllowance,
+                &VERIFIER,
+                &DataCap::zero(),
+            ),
+        );
+        rt.reset();
+
+        // Decrease beyond the current allowance.
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            h.change_verifier_allowance(
+                &mut rt,
+                Method::DecreaseVerifierAllowance,
+                &VERIFIER,
+                &(allowance.clone() + 1),
+            ),
+        );
+        rt.reset();
+
+        // Decrease to zero.
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            h.change_verifier_allowance(
+                &mut rt,
+                Method::DecreaseVerifierAllowance,
+                &VERIFIER,
+                &allowance,
+            ),
+        );
+        rt.reset();
+
+        // Decrease to a non-zero allowance below the minimum.
+        let minimum = rt.policy.minimum_verified_allocation_size.clone();
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            h.change_verifier_allowance(
+                &mut rt,
+                Method::DecreaseVerifierAllowance,
+                &VERIFIER,
+                &(&allowance - &minimum + 1),
+            ),
+        );
+        rt.reset();
+        h.assert_verifier_allowance(&rt, &VERIFIER, &allowance);
+        h.check_state(&rt);
+    }
+
+    #[test]
+    fn update_verifiers_reports_per_item_results() {
+        let (h, mut rt) = new_harness();
+        let allowance = verifier_allowance(&rt);
+        h.add_verifier(&mut rt, &VERIFIER, &allowance).unwrap();
+        let not_verifier = Address::new_id(203);
+
+        let updates = vec![
+            // Add a new verifier.
+            VerifierUpdate { address: *VERIFIER2, allowance: allowance.clone() },
+            // Remove an existing verifier.
+            VerifierUpdate { address: *VERIFIER, allowance: DataCap::zero() },
+            // Remove an address which isn't a verifier.
+            VerifierUpdate { address: not_verifier, allowance: DataCap::zero() },
+            // Add a verified client.
+            VerifierUpdate { address: *CLIENT, allowance: allowance.clone() },
+            // Add with an allowance below the minimum.
+            VerifierUpdate { address: not_verifier, allowance: DataCap::from(1) },
+        ];
+        let balances = [(*VERIFIER2, DataCap::zero()), (*CLIENT, DataCap::from(1))];
+        let ret = h.update_verifiers(&mut rt, updates, &balances, false).unwrap();
+        assert_eq!(
+            vec![
+                ExitCode::OK,
+                ExitCode::OK,
+                ExitCode::USR_NOT_FOUND,
+                ExitCode::USR_ILLEGAL_ARGUMENT,
+                ExitCode::USR_ILLEGAL_ARGUMENT
+            ],
+            ret.codes()
+        );
+
+        h.assert_verifier_allowance(&rt, &VERIFIER2, &allowance);
+        h.assert_verifier_removed(&rt, &VERIFIER);
+        h.assert_verifier_removed(&rt, &CLIENT);
+        h.assert_verifier_removed(&rt, &not_verifier);
+
+        // Setting an allowance replaces the existing one.
+        let new_allowance = allowance.clone() + 10;
+        let updates =
+            vec![VerifierUpdate { address: *VERIFIER2, allowance: new_allowance.clone() }];
+        let ret =
+            h.update_verifiers(&mut rt, updates, &[(*VERIFIER2, DataCap::zero())], true).unwrap();
+        assert!(ret.all_ok());
+        h.assert_verifier_allowance(&rt, &VERIFIER2, &new_allowance);
+        h.check_state(&rt);
+    }
+
+    #[test]
+    fn update_verifiers_all_or_nothing() {
+        let (h, mut rt) = new_harness();
+        let allowance = verifier_allowance(&rt);
+        h.add_verifier(&mut rt, &VERIFIER, &allowance).unwrap();
+
+        let updates = vec![
+            VerifierUpdate { address: *VERIFIER, allowance: DataCap::zero() },
+            VerifierUpdate { address: *VERIFIER2, allowance: DataCap::zero() },
+        ];
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            h.update_verifiers(&mut rt, updates, &[], true),
+        );
+        rt.reset();
+        h.assert_verifier_allowance(&rt, &VERIFIER, &allowance);
+        h.check_state(&rt);
+    }
+
+    #[test]
+    fn update_verifiers_requires_root_and_updates() {
+        let (h, mut rt) = new_harness();
+        let allowance = verifier_allowance(&rt);
+
+        rt.expect_validate_caller_addr(vec![h.root]);
+        rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(501));
+        let params = UpdateVerifiersParams {
+            updates: vec![VerifierUpdate { address: *VERIFIER, allowance }],
+            all_or_nothing: false,
+        };
+        expect_abort(
+            ExitCode::USR_FORBIDDEN,
+            rt.call::<VerifregActor>(
+                Method::UpdateVerifiers as MethodNum,
+                &RawBytes::serialize(params).unwrap(),
+            ),
+        );
+        rt.reset();
+
+        expect_abort(
+            ExitCode::USR_ILLEGAL_ARGUMENT,
+            h.update_verifiers(&mut rt, vec![], &[], false),
+        );
+        rt.reset();
+        h.check_state(&rt);
+    }
 }
 
 mod clients {
@@ -661,6 +875,25 @@ mod allocs_claims {
         }
     }
 
+    #[test]
+    fn get_allocation() {
+        let (h, mut rt) = new_harness();
+        let alloc1 = make_alloc("1", CLIENT1, PROVIDER1, ALLOC_SIZE);
+        let alloc2 = make_alloc("2", CLIENT2, PROVIDER1, ALLOC_SIZE);
+        let id1 = h.create_alloc(&mut rt, &alloc1).unwrap();
+        let id2 = h.create_alloc(&mut rt, &alloc2).unwrap();
+
+        assert_eq!(alloc1, h.get_allocation(&mut rt, CLIENT1, id1).unwrap());
+        assert_eq!(alloc2, h.get_allocation(&mut rt, CLIENT2, id2).unwrap());
+
+        // The allocation must belong to the given client.
+        expect_abort(ExitCode::USR_NOT_FOUND, h.get_allocation(&mut rt, CLIENT1, id2));
+        rt.reset();
+        expect_abort(ExitCode::USR_NOT_FOUND, h.get_allocation(&mut rt, CLIENT1, id2 + 1));
+        rt.reset();
+        h.check_state(&rt);
+    }
+
     #[test]
     fn get_claims() {
         let (h, mut rt) = new_harness();
@@ -0,0 +1,44 @@
+#![no_main]
+
+use fil_actor_market::{Actor, Method, State};
+use fil_actors_runtime::test_utils::{MockRuntime, ACCOUNT_ACTOR_CODE_ID};
+use fil_actors_runtime::{CALLER_TYPES_SIGNABLE, STORAGE_MARKET_ACTOR_ADDR};
+use fil_builtin_actors_fuzz::{invoke_method, Callable};
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use libfuzzer_sys::fuzz_target;
+
+const CLIENT: u64 = 100;
+
+// No actor codes are registered, not even the caller's, so no address in the parameters
+// resolves to an actor and each method stops before it would send to one.
+fn runtime() -> MockRuntime {
+    let mut rt = MockRuntime::default();
+    rt.receiver = STORAGE_MARKET_ACTOR_ADDR;
+    rt.caller = Address::new_id(CLIENT);
+    rt.caller_type = *ACCOUNT_ACTOR_CODE_ID;
+    rt.set_value(TokenAmount::from_whole(1));
+    let st = State::new(&*rt.store).unwrap();
+    rt.replace_state(&st);
+    rt
+}
+
+fn signable(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_type(CALLER_TYPES_SIGNABLE.to_vec());
+}
+
+// Withdrawal resolves the escrow address before checking the caller, and never gets that far.
+fn unchecked(_: &mut MockRuntime) {}
+
+fn anyone(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_any();
+}
+
+const METHODS: &[Callable] = &[
+    Callable { method: Method::AddBalance as u64, prepare: signable },
+    Callable { method: Method::WithdrawBalance as u64, prepare: unchecked },
+    Callable { method: Method::PublishStorageDeals as u64, prepare: signable },
+    Callable { method: Method::GetDealAllocationId as u64, prepare: anyone },
+];
+
+fuzz_target!(|data: &[u8]| invoke_method::<Actor>(runtime(), METHODS, data));
@@ -0,0 +1,101 @@
+#![no_main]
+
+use cid::multihash::Code::Blake2b256;
+use fil_actor_miner::{Actor, Method, MinerInfo, State};
+use fil_actors_runtime::runtime::builtins::Type;
+use fil_actors_runtime::test_utils::{MockRuntime, MINER_ACTOR_CODE_ID};
+use fil_builtin_actors_fuzz::{invoke_method, Callable};
+use fvm_ipld_encoding::CborStore;
+use fvm_shared::address::Address;
+use fvm_shared::sector::RegisteredPoStProof;
+use libfuzzer_sys::fuzz_target;
+
+const RECEIVER: u64 = 1000;
+const OWNER: u64 = 100;
+const WORKER: u64 = 101;
+const OTHER_MINER: u64 = 1001;
+
+// The caller is another miner, so it is neither a control address nor a signable actor.
+// Methods restricted to those callers stop at the caller check, before sending to the power,
+// reward or market actors. Only the caller's code is registered, so no address in the
+// parameters resolves to an account which the miner would query for its key.
+fn runtime() -> MockRuntime {
+    let mut rt = MockRuntime::default();
+    rt.receiver = Address::new_id(RECEIVER);
+    rt.set_caller(*MINER_ACTOR_CODE_ID, Address::new_id(OTHER_MINER));
+    let info = MinerInfo::new(
+        Address::new_id(OWNER),
+        Address::new_id(WORKER),
+        vec![],
+        vec![],
+        vec![],
+        RegisteredPoStProof::StackedDRGWindow32GiBV1,
+    )
+    .unwrap();
+    let info_cid = rt.store.put_cbor(&info, Blake2b256).unwrap();
+    let st = State::new(&rt.policy, &*rt.store, info_cid, 0, 0).unwrap();
+    rt.replace_state(&st);
+    rt
+}
+
+fn owner(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_addr(vec![Address::new_id(OWNER)]);
+}
+
+// The beneficiary starts out as the owner.
+fn owner_or_beneficiary(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_addr(vec![Address::new_id(OWNER), Address::new_id(OWNER)]);
+}
+
+fn worker_or_owner(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_addr(vec![Address::new_id(WORKER), Address::new_id(OWNER)]);
+}
+
+fn owner_or_worker(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_addr(vec![Address::new_id(OWNER), Address::new_id(WORKER)]);
+}
+
+fn signable(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_type(vec![Type::Account, Type::Multisig]);
+}
+
+fn anyone(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_any();
+}
+
+// Pre-commits, aggregate prove-commits, sector and partition compaction, the termination fee
+// and the pledge estimates charge gas or send before checking the caller, and are left to the
+// decoding targets.
+const METHODS: &[Callable] = &[
+    Callable { method: Method::ControlAddresses as u64, prepare: anyone },
+    Callable { method: Method::ChangeWorkerAddress as u64, prepare: owner },
+    Callable { method: Method::ChangePeerID as u64, prepare: worker_or_owner },
+    Callable { method: Method::SubmitWindowedPoSt as u64, prepare: worker_or_owner },
+    Callable { method: Method::ProveCommitSector as u64, prepare: anyone },
+    Callable { method: Method::ExtendSectorExpiration as u64, prepare: worker_or_owner },
+    Callable { method: Method::TerminateSectors as u64, prepare: worker_or_owner },
+    Callable { method: Method::DeclareFaults as u64, prepare: worker_or_owner },
+    Callable { method: Method::DeclareFaultsRecovered as u64, prepare: worker_or_owner },
+    Callable { method: Method::CheckSectorProven as u64, prepare: anyone },
+    Callable { method: Method::ReportConsensusFault as u64, prepare: signable },
+    Callable { method: Method::WithdrawBalance as u64, prepare: owner_or_beneficiary },
+    Callable { method: Method::ChangeMultiaddrs as u64, prepare: worker_or_owner },
+    Callable { method: Method::ConfirmUpdateWorkerKey as u64, prepare: owner },
+    Callable { method: Method::RepayDebt as u64, prepare: worker_or_owner },
+    Callable { method: Method::ChangeOwnerAddress as u64, prepare: owner },
+    Callable { method: Method::DisputeWindowedPoSt as u64, prepare: signable },
+    Callable { method: Method::ProveReplicaUpdates as u64, prepare: owner_or_worker },
+    Callable { method: Method::ProveReplicaUpdates2 as u64, prepare: owner_or_worker },
+    Callable { method: Method::ChangeBeneficiary as u64, prepare: anyone },
+    Callable { method: Method::GetBeneficiary as u64, prepare: anyone },
+    Callable { method: Method::ExtendSectorExpiration2 as u64, prepare: worker_or_owner },
+    Callable { method: Method::CancelChangeWorkerAddress as u64, prepare: owner },
+    Callable { method: Method::GetOnboardingInfo as u64, prepare: anyone },
+    Callable { method: Method::GetDeadlineInfo as u64, prepare: anyone },
+    Callable { method: Method::GetDeadlinePartitionCount as u64, prepare: anyone },
+    Callable { method: Method::GetPartitionSectors as u64, prepare: anyone },
+    Callable { method: Method::GetSectorInfo as u64, prepare: anyone },
+    Callable { method: Method::ReportConsensusFaultExported as u64, prepare: signable },
+];
+
+fuzz_target!(|data: &[u8]| invoke_method::<Actor>(runtime(), METHODS, data));
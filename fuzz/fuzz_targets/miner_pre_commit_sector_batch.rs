@@ -0,0 +1,6 @@
+#![no_main]
+
+use fil_builtin_actors_fuzz::decode_params;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| decode_params::<fil_actor_miner::PreCommitSectorBatchParams2>(data));
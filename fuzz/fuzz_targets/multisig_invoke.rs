@@ -0,0 +1,68 @@
+#![no_main]
+
+use fil_actor_multisig::{Actor, Method, State};
+use fil_actors_runtime::make_empty_map;
+use fil_actors_runtime::runtime::builtins::Type;
+use fil_actors_runtime::test_utils::{MockRuntime, ACCOUNT_ACTOR_CODE_ID, MULTISIG_ACTOR_CODE_ID};
+use fil_builtin_actors_fuzz::{invoke_method, Callable};
+use fvm_shared::address::Address;
+use fvm_shared::HAMT_BIT_WIDTH;
+use libfuzzer_sys::fuzz_target;
+
+const RECEIVER: u64 = 1000;
+const SIGNER: u64 = 100;
+const OTHER_SIGNER: u64 = 101;
+
+// With two approvals required, a proposal is stored rather than executed.
+fn runtime() -> MockRuntime {
+    let mut rt = MockRuntime::default();
+    rt.receiver = Address::new_id(RECEIVER);
+    let pending_txs = make_empty_map::<_, ()>(&*rt.store, HAMT_BIT_WIDTH).flush().unwrap();
+    rt.replace_state(&State {
+        signers: vec![Address::new_id(SIGNER), Address::new_id(OTHER_SIGNER)],
+        num_approvals_threshold: 2,
+        next_tx_id: Default::default(),
+        initial_balance: Default::default(),
+        start_epoch: 0,
+        unlock_duration: 0,
+        pending_txs,
+    });
+    rt
+}
+
+fn signer(rt: &mut MockRuntime) {
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(SIGNER));
+    rt.expect_validate_caller_type(vec![Type::Account, Type::Multisig]);
+}
+
+fn anyone(rt: &mut MockRuntime) {
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(SIGNER));
+    rt.expect_validate_caller_any();
+}
+
+fn receiver(rt: &mut MockRuntime) {
+    rt.set_caller(*MULTISIG_ACTOR_CODE_ID, Address::new_id(RECEIVER));
+    rt.expect_validate_caller_addr(vec![Address::new_id(RECEIVER)]);
+}
+
+// Signer changes resolve the new signer, which may send, so these calls come from a signer
+// rather than the multisig itself and are rejected.
+fn signer_not_receiver(rt: &mut MockRuntime) {
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(SIGNER));
+    rt.expect_validate_caller_addr(vec![Address::new_id(RECEIVER)]);
+}
+
+const METHODS: &[Callable] = &[
+    Callable { method: Method::Propose as u64, prepare: signer },
+    Callable { method: Method::Approve as u64, prepare: signer },
+    Callable { method: Method::Cancel as u64, prepare: signer },
+    Callable { method: Method::AddSigner as u64, prepare: signer_not_receiver },
+    Callable { method: Method::RemoveSigner as u64, prepare: signer_not_receiver },
+    Callable { method: Method::SwapSigner as u64, prepare: signer_not_receiver },
+    Callable { method: Method::ChangeNumApprovalsThreshold as u64, prepare: receiver },
+    Callable { method: Method::LockBalance as u64, prepare: receiver },
+    Callable { method: Method::UniversalReceiverHook as u64, prepare: anyone },
+    Callable { method: Method::GetTransaction as u64, prepare: anyone },
+];
+
+fuzz_target!(|data: &[u8]| invoke_method::<Actor>(runtime(), METHODS, data));
@@ -0,0 +1,41 @@
+#![no_main]
+
+use fil_actor_power::{Actor, Method, State};
+use fil_actors_runtime::runtime::builtins::Type;
+use fil_actors_runtime::test_utils::{MockRuntime, MINER_ACTOR_CODE_ID};
+use fil_actors_runtime::STORAGE_POWER_ACTOR_ADDR;
+use fil_builtin_actors_fuzz::{invoke_method, Callable};
+use fvm_shared::address::Address;
+use libfuzzer_sys::fuzz_target;
+
+const MINER: u64 = 1000;
+
+// The caller is a miner, which may read power but may not create miners:
+// miner creation goes on to send to the init actor.
+fn runtime() -> MockRuntime {
+    let mut rt = MockRuntime::default();
+    rt.receiver = STORAGE_POWER_ACTOR_ADDR;
+    rt.set_caller(*MINER_ACTOR_CODE_ID, Address::new_id(MINER));
+    let st = State::new(&*rt.store).unwrap();
+    rt.replace_state(&st);
+    rt
+}
+
+fn signable(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_type(vec![Type::Account, Type::Multisig]);
+}
+
+fn anyone(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_any();
+}
+
+const METHODS: &[Callable] = &[
+    Callable { method: Method::CreateMiner as u64, prepare: signable },
+    Callable { method: Method::CurrentTotalPower as u64, prepare: anyone },
+    Callable { method: Method::MinerPower as u64, prepare: anyone },
+    Callable { method: Method::NetworkQAPower as u64, prepare: anyone },
+    Callable { method: Method::TotalPledgeCollateral as u64, prepare: anyone },
+    Callable { method: Method::MinerConsensusEligibility as u64, prepare: anyone },
+];
+
+fuzz_target!(|data: &[u8]| invoke_method::<Actor>(runtime(), METHODS, data));
@@ -0,0 +1,42 @@
+#![no_main]
+
+use fil_actor_verifreg::{Actor, Method, State};
+use fil_actors_runtime::test_utils::{MockRuntime, ACCOUNT_ACTOR_CODE_ID};
+use fil_actors_runtime::VERIFIED_REGISTRY_ACTOR_ADDR;
+use fil_builtin_actors_fuzz::{invoke_method, Callable};
+use fvm_shared::address::Address;
+use libfuzzer_sys::fuzz_target;
+
+const ROOT_KEY: u64 = 100;
+const CLIENT: u64 = 101;
+
+// The caller is a client rather than the root key, so verifier changes stop at the caller check
+// before resolving any address, which may send. Claim methods touch only the registry's state.
+fn runtime() -> MockRuntime {
+    let mut rt = MockRuntime::default();
+    rt.receiver = VERIFIED_REGISTRY_ACTOR_ADDR;
+    rt.set_caller(*ACCOUNT_ACTOR_CODE_ID, Address::new_id(CLIENT));
+    let st = State::new(&*rt.store, Address::new_id(ROOT_KEY)).unwrap();
+    rt.replace_state(&st);
+    rt
+}
+
+fn root_key(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_addr(vec![Address::new_id(ROOT_KEY)]);
+}
+
+fn anyone(rt: &mut MockRuntime) {
+    rt.expect_validate_caller_any();
+}
+
+const METHODS: &[Callable] = &[
+    Callable { method: Method::IncreaseVerifierAllowance as u64, prepare: root_key },
+    Callable { method: Method::DecreaseVerifierAllowance as u64, prepare: root_key },
+    Callable { method: Method::UpdateVerifiers as u64, prepare: root_key },
+    Callable { method: Method::GetClaims as u64, prepare: anyone },
+    Callable { method: Method::ExtendClaimTerms as u64, prepare: anyone },
+    Callable { method: Method::RemoveExpiredClaims as u64, prepare: anyone },
+    Callable { method: Method::GetAllocation as u64, prepare: anyone },
+];
+
+fuzz_target!(|data: &[u8]| invoke_method::<Actor>(runtime(), METHODS, data));
@@ -0,0 +1,52 @@
+//! Shared checks for the fuzz targets.
+//!
+//! The decoding targets feed arbitrary bytes to the same parameter decoding an actor's
+//! `invoke_method` performs. Decoding must fail with an error rather than panic, and anything which
+//! does decode must survive a round trip through serialization.
+//!
+//! The invoke targets go further, dispatching arbitrary bytes through an actor's `invoke_method` on
+//! a mock runtime. The mock panics on any call it was not told to expect, so each target prepares
+//! it for the method being called: accepted callers for methods which only touch the actor's own
+//! state, and callers rejected by the method's own caller check where it would go on to send.
+//! Methods which send, charge gas or draw randomness before checking their caller are left to the
+//! decoding targets.
+
+use fil_actors_runtime::cbor;
+use fil_actors_runtime::runtime::ActorCode;
+use fil_actors_runtime::test_utils::MockRuntime;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::MethodNum;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Decodes `data` as method parameters of type `T`, checking the round trip of any success.
+pub fn decode_params<T: DeserializeOwned + Serialize>(data: &[u8]) {
+    let params = RawBytes::new(data.to_vec());
+    if let Ok(decoded) = cbor::deserialize_params::<T>(&params) {
+        let encoded = cbor::serialize(&decoded, "fuzzed parameters").unwrap();
+        cbor::deserialize_params::<T>(&encoded).unwrap();
+    }
+}
+
+/// A method which users may call, with the preparation of a mock runtime for calling it.
+pub struct Callable {
+    pub method: MethodNum,
+    /// Sets the caller and expects the caller validation which the method performs.
+    pub prepare: fn(&mut MockRuntime),
+}
+
+/// Invokes one of `methods` of actor `A` on `rt`, as a message from a user would.
+/// The first byte of `data` selects the method and the rest are its parameters.
+///
+/// The call may fail, but must not panic. Expectations the call did not reach,
+/// such as caller validation after a parameter error, are cleared afterwards.
+pub fn invoke_method<A: ActorCode>(mut rt: MockRuntime, methods: &[Callable], data: &[u8]) {
+    let (selector, params) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    let callable = &methods[*selector as usize % methods.len()];
+    (callable.prepare)(&mut rt);
+    let _ = rt.call::<A>(callable.method, &RawBytes::new(params.to_vec()));
+    rt.reset();
+}
@@ -7,6 +7,7 @@ use fvm_sdk as fvm;
 use fvm_sdk::NO_DATA_BLOCK_ID;
 use fvm_shared::address::Address;
 use fvm_shared::clock::ChainEpoch;
+use fvm_shared::crypto::hash::SupportedHashes;
 use fvm_shared::crypto::signature::Signature;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::{ErrorNumber, ExitCode};
@@ -18,7 +19,7 @@ use fvm_shared::sector::{
 };
 use fvm_shared::version::NetworkVersion;
 use fvm_shared::{ActorID, MethodNum};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, Zero};
 #[cfg(feature = "fake-proofs")]
 use sha2::{Digest, Sha256};
 
@@ -70,6 +71,16 @@ impl<B> FvmRuntime<B> {
     fn policy_mut(&mut self) -> &mut Policy {
         &mut self.policy
     }
+
+    fn assert_not_read_only(&self, operation: &str) -> Result<(), ActorError>
+    where
+        B: Blockstore,
+    {
+        if self.read_only() {
+            return Err(actor_error!(forbidden; "{} is not allowed in read-only mode", operation));
+        }
+        Ok(())
+    }
 }
 
 /// A stub MessageInfo implementation performing FVM syscalls to obtain its fields.
@@ -226,6 +237,7 @@ where
     }
 
     fn create<C: Cbor>(&mut self, obj: &C) -> Result<(), ActorError> {
+        self.assert_not_read_only("create")?;
         let root = fvm::sself::root()?;
         if root != EMPTY_ARR_CID {
             return Err(
@@ -251,6 +263,7 @@ where
         C: Cbor,
         F: FnOnce(&mut C, &mut Self) -> Result<RT, ActorError>,
     {
+        self.assert_not_read_only("transaction")?;
         let state_cid = fvm::sself::root()
             .map_err(|_| actor_error!(illegal_argument; "failed to get actor root state CID"))?;
 
@@ -286,6 +299,9 @@ where
         if self.in_transaction {
             return Err(actor_error!(assertion_failed; "send is not allowed during transaction"));
         }
+        if !value.is_zero() {
+            self.assert_not_read_only("send with value")?;
+        }
         match fvm::send::send(to, method, params, value) {
             Ok(ret) => {
                 if ret.exit_code.is_success() {
@@ -345,12 +361,24 @@ where
         Ok(fvm::actor::new_actor_address())
     }
 
-    fn create_actor(&mut self, code_id: Cid, actor_id: ActorID) -> Result<(), ActorError> {
+    fn create_actor(
+        &mut self,
+        code_id: Cid,
+        actor_id: ActorID,
+        predictable_address: Option<Address>,
+    ) -> Result<(), ActorError> {
         if self.in_transaction {
             return Err(
                 actor_error!(assertion_failed; "create_actor is not allowed during transaction"),
             );
         }
+        self.assert_not_read_only("create_actor")?;
+        // The SDK's create_actor syscall cannot bind a delegated address.
+        if let Some(addr) = predictable_address {
+            return Err(
+                actor_error!(illegal_argument; "delegated address {} is not supported", addr),
+            );
+        }
         fvm::actor::create_actor(actor_id, &code_id).map_err(|e| match e {
             ErrorNumber::IllegalArgument => {
                 ActorError::illegal_argument("failed to create actor".into())
@@ -365,6 +393,7 @@ where
                 actor_error!(assertion_failed; "delete_actor is not allowed during transaction"),
             );
         }
+        self.assert_not_read_only("delete_actor")?;
         Ok(fvm::sself::self_destruct(beneficiary)?)
     }
 
@@ -379,6 +408,11 @@ where
     fn base_fee(&self) -> TokenAmount {
         fvm::network::base_fee()
     }
+
+    fn read_only(&self) -> bool {
+        // The FVM SDK in use has no read-only invocations, so every invocation may mutate state.
+        false
+    }
 }
 
 impl<B> Primitives for FvmRuntime<B>
@@ -401,6 +435,10 @@ where
         fvm::crypto::hash_blake2b(data)
     }
 
+    fn hash(&self, hasher: SupportedHashes, data: &[u8]) -> Vec<u8> {
+        fvm::crypto::hash(hasher, data)
+    }
+
     fn compute_unsealed_sector_cid(
         &self,
         proof_type: RegisteredSealProof,
@@ -7,6 +7,7 @@ use fvm_ipld_encoding::{Cbor, RawBytes};
 use fvm_shared::address::Address;
 use fvm_shared::clock::ChainEpoch;
 use fvm_shared::consensus::ConsensusFault;
+use fvm_shared::crypto::hash::SupportedHashes;
 use fvm_shared::crypto::signature::Signature;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::piece::PieceInfo;
@@ -138,11 +139,19 @@ pub trait Runtime: Primitives + Verifier + RuntimePolicy {
     fn new_actor_address(&mut self) -> Result<Address, ActorError>;
 
     /// Creates an actor with code `codeID` and address `address`, with empty state.
-    /// May only be called by Init actor.
-    fn create_actor(&mut self, code_id: Cid, address: ActorID) -> Result<(), ActorError>;
+    /// If `predictable_address` is provided, it is bound to the new actor as its delegated (f4)
+    /// address. May only be called by Init actor.
+    fn create_actor(
+        &mut self,
+        code_id: Cid,
+        address: ActorID,
+        predictable_address: Option<Address>,
+    ) -> Result<(), ActorError>;
 
     /// Deletes the executing actor from the state tree, transferring any balance to beneficiary.
-    /// Aborts if the beneficiary does not exist.
+    /// Aborts if the beneficiary does not exist or is the executing actor itself.
+    /// The deleted actor's address no longer refers to any actor, so subsequent messages to it
+    /// fail (or, for a public key address, create a new account actor).
     /// May only be called by the actor itself.
     fn delete_actor(&mut self, beneficiary: &Address) -> Result<(), ActorError>;
 
@@ -168,6 +177,12 @@ pub trait Runtime: Primitives + Verifier + RuntimePolicy {
     fn charge_gas(&mut self, name: &'static str, compute: i64);
 
     fn base_fee(&self) -> TokenAmount;
+
+    /// Returns true if the current invocation is read-only.
+    /// A read-only invocation may not mutate or create its own state, transfer value,
+    /// or create or delete actors. Runtime methods performing such operations return
+    /// a forbidden error when called in a read-only context.
+    fn read_only(&self) -> bool;
 }
 
 /// Message information available to the actor about executing message.
@@ -188,6 +203,19 @@ pub trait Primitives {
     /// Hashes input data using blake2b with 256 bit output.
     fn hash_blake2b(&self, data: &[u8]) -> [u8; 32];
 
+    /// Hashes input data using the specified hash function, returning the full digest.
+    fn hash(&self, hasher: SupportedHashes, data: &[u8]) -> Vec<u8>;
+
+    /// Hashes input data using the specified hash function, writing the digest into `digest`.
+    /// The digest is truncated if it is longer than the buffer.
+    /// Returns the number of bytes written.
+    fn hash_into(&self, hasher: SupportedHashes, data: &[u8], digest: &mut [u8]) -> usize {
+        let hash = self.hash(hasher, data);
+        let len = hash.len().min(digest.len());
+        digest[..len].copy_from_slice(&hash[..len]);
+        len
+    }
+
     /// Computes an unsealed sector CID (CommD) from its constituent piece CIDs (CommPs) and sizes.
     fn compute_unsealed_sector_cid(
         &self,
@@ -229,12 +257,16 @@ pub trait Verifier {
         extra: &[u8],
     ) -> Result<Option<ConsensusFault>, anyhow::Error>;
 
+    /// Verifies a batch of sector seal proofs, returning the validity of each proof
+    /// in the same order as the input.
     fn batch_verify_seals(&self, batch: &[SealVerifyInfo]) -> anyhow::Result<Vec<bool>>;
 
+    /// Verifies an aggregate proof of the seals of many sectors.
     fn verify_aggregate_seals(
         &self,
         aggregate: &AggregateSealVerifyProofAndInfos,
     ) -> Result<(), anyhow::Error>;
 
+    /// Verifies a proof that a sector's replica has been updated with new data.
     fn verify_replica_update(&self, replica: &ReplicaUpdateInfo) -> Result<(), anyhow::Error>;
 }
@@ -1,7 +1,9 @@
 use std::collections::HashSet;
+use std::str::FromStr;
 
 use fvm_shared::bigint::bigint_ser;
 use fvm_shared::clock::ChainEpoch;
+use fvm_shared::econ::TokenAmount;
 use fvm_shared::sector::{RegisteredPoStProof, RegisteredSealProof, StoragePower};
 use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
@@ -165,10 +167,26 @@ pub struct Policy {
     /// allocation's maximum term.
     pub market_default_allocation_term_buffer: i64,
 
+    // --- paych ---
+    /// The maximum number of vouchers that may be redeemed in a single batch.
+    pub paych_updates_batch_max_size: usize,
+
     // --- power ---
     /// Minimum miner consensus power
     #[serde(with = "bigint_ser")]
     pub minimum_consensus_power: StoragePower,
+
+    // --- reward ---
+    /// Q.128 factor by which the baseline power target grows each epoch.
+    #[serde(with = "bigint_ser")]
+    pub reward_baseline_exponent: StoragePower,
+    /// Baseline power target at the first epoch.
+    #[serde(with = "bigint_ser")]
+    pub reward_baseline_initial_value: StoragePower,
+    /// Total tokens minted on the simple (time-based) schedule.
+    pub reward_simple_total: TokenAmount,
+    /// Total tokens minted on the baseline (network-time) schedule.
+    pub reward_baseline_total: TokenAmount,
 }
 
 impl Default for Policy {
@@ -251,7 +269,19 @@ impl Default for Policy {
             market_default_allocation_term_buffer:
                 policy_constants::MARKET_DEFAULT_ALLOCATION_TERM_BUFFER,
 
+            paych_updates_batch_max_size: policy_constants::PAYCH_UPDATES_BATCH_MAX_SIZE,
+
             minimum_consensus_power: StoragePower::from(policy_constants::MINIMUM_CONSENSUS_POWER),
+
+            reward_baseline_exponent: StoragePower::from_str(
+                policy_constants::REWARD_BASELINE_EXPONENT,
+            )
+            .unwrap(),
+            reward_baseline_initial_value: StoragePower::from(
+                policy_constants::REWARD_BASELINE_INITIAL_VALUE,
+            ),
+            reward_simple_total: TokenAmount::from_whole(policy_constants::REWARD_SIMPLE_TOTAL),
+            reward_baseline_total: TokenAmount::from_whole(policy_constants::REWARD_BASELINE_TOTAL),
         }
     }
 }
@@ -407,6 +437,10 @@ pub mod policy_constants {
 
     pub const MARKET_DEFAULT_ALLOCATION_TERM_BUFFER: i64 = 90 * EPOCHS_IN_DAY;
 
+    /// The maximum number of vouchers that may be redeemed in a single batch.
+    /// Each voucher's signature is verified, so this bounds the cost of a single message.
+    pub const PAYCH_UPDATES_BATCH_MAX_SIZE: usize = 256;
+
     #[cfg(feature = "min-power-2k")]
     pub const MINIMUM_CONSENSUS_POWER: i64 = 2 << 10;
     #[cfg(feature = "min-power-2g")]
@@ -419,4 +453,16 @@ pub mod policy_constants {
         feature = "min-power-32g"
     )))]
     pub const MINIMUM_CONSENSUS_POWER: i64 = 10 << 40;
+
+    /// Floor(e^(ln[1 + 200%] / epochsInYear) * 2^128)
+    /// Q.128 formatted number such that f(epoch) = baseExponent^epoch grows 200% in one
+    /// year of epochs. It doesn't fit in a u128, so is given in decimal.
+    /// Calculation here: https://www.wolframalpha.com/input/?i=IntegerPart%5BExp%5BLog%5B1%2B200%25%5D%2F%28%28365+days%29%2F%2830+seconds%29%29%5D*2%5E128%5D
+    pub const REWARD_BASELINE_EXPONENT: &str = "340282591298641078465964189926313473653";
+    /// 2.5057116798121726 EiB
+    pub const REWARD_BASELINE_INITIAL_VALUE: u128 = 2_888_888_880_000_000_000;
+    /// 330M FIL for mainnet
+    pub const REWARD_SIMPLE_TOTAL: u64 = 330_000_000;
+    /// 770M FIL for mainnet
+    pub const REWARD_BASELINE_TOTAL: u64 = 770_000_000;
 }
@@ -17,6 +17,7 @@ use fvm_shared::address::{Address, Protocol};
 use fvm_shared::clock::ChainEpoch;
 use fvm_shared::commcid::{FIL_COMMITMENT_SEALED, FIL_COMMITMENT_UNSEALED};
 use fvm_shared::consensus::ConsensusFault;
+use fvm_shared::crypto::hash::SupportedHashes;
 use fvm_shared::crypto::signature::Signature;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::ExitCode;
@@ -32,6 +33,7 @@ use fvm_shared::{ActorID, MethodNum};
 use multihash::derive::Multihash;
 use multihash::MultihashDigest;
 
+use num_traits::Zero;
 use rand::prelude::*;
 
 use crate::runtime::builtins::Type;
@@ -134,6 +136,8 @@ pub struct MockRuntime<BS = MemoryBlockstore> {
     pub policy: Policy,
 
     pub circulating_supply: TokenAmount,
+
+    pub read_only: bool,
 }
 
 #[derive(Default)]
@@ -281,6 +285,7 @@ impl<BS> MockRuntime<BS> {
             expectations: Default::default(),
             policy: Default::default(),
             circulating_supply: Default::default(),
+            read_only: false,
         }
     }
 }
@@ -289,6 +294,7 @@ impl<BS> MockRuntime<BS> {
 pub struct ExpectCreateActor {
     pub code_id: Cid,
     pub actor_id: ActorID,
+    pub predictable_address: Option<Address>,
 }
 
 #[derive(Clone, Debug)]
@@ -368,6 +374,14 @@ pub struct ExpectReplicaVerify {
     result: anyhow::Result<()>,
 }
 
+/// The receiver's state root and balance at some point in a test, captured by
+/// `MockRuntime::snapshot`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockRuntimeSnapshot {
+    pub state: Option<Cid>,
+    pub balance: TokenAmount,
+}
+
 pub fn expect_empty(res: RawBytes) {
     assert_eq!(res, RawBytes::default());
 }
@@ -467,6 +481,28 @@ impl<BS: Blockstore> MockRuntime<BS> {
         res
     }
 
+    /// Captures the receiver's state root and balance.
+    pub fn snapshot(&self) -> MockRuntimeSnapshot {
+        MockRuntimeSnapshot { state: self.state, balance: self.get_balance() }
+    }
+
+    /// Restores the receiver's state root and balance to those of a snapshot.
+    /// Expectations are not affected.
+    pub fn rollback(&mut self, snapshot: MockRuntimeSnapshot) {
+        self.state = snapshot.state;
+        self.set_balance(snapshot.balance);
+    }
+
+    /// Asserts that the receiver's state root and balance are those of a snapshot,
+    /// e.g. after a call that aborted.
+    pub fn assert_unchanged_since(&self, snapshot: &MockRuntimeSnapshot) {
+        assert_eq!(
+            snapshot,
+            &self.snapshot(),
+            "expected state and balance to be unchanged since snapshot"
+        );
+    }
+
     /// Verifies that all mock expectations have been met.
     pub fn verify(&mut self) {
         self.expectations.borrow_mut().verify()
@@ -560,8 +596,13 @@ impl<BS: Blockstore> MockRuntime<BS> {
     }
 
     #[allow(dead_code)]
-    pub fn expect_create_actor(&mut self, code_id: Cid, actor_id: ActorID) {
-        let a = ExpectCreateActor { code_id, actor_id };
+    pub fn expect_create_actor(
+        &mut self,
+        code_id: Cid,
+        actor_id: ActorID,
+        predictable_address: Option<Address>,
+    ) {
+        let a = ExpectCreateActor { code_id, actor_id, predictable_address };
         self.expectations.borrow_mut().expect_create_actor = Some(a);
     }
 
@@ -658,6 +699,13 @@ impl<BS: Blockstore> MockRuntime<BS> {
         assert!(self.in_call, "invalid runtime invocation outside of method call")
     }
 
+    fn require_not_read_only(&self, operation: &str) -> Result<(), ActorError> {
+        if self.read_only {
+            return Err(actor_error!(forbidden; "{} is not allowed in read-only mode", operation));
+        }
+        Ok(())
+    }
+
     fn store_put<C: Cbor>(&self, o: &C) -> Cid {
         self.store.put_cbor(&o, Code::Blake2b256).unwrap()
     }
@@ -865,6 +913,7 @@ impl<BS: Blockstore> Runtime for MockRuntime<BS> {
     }
 
     fn create<C: Cbor>(&mut self, obj: &C) -> Result<(), ActorError> {
+        self.require_not_read_only("create")?;
         if self.state.is_some() {
             return Err(actor_error!(illegal_state; "state already constructed"));
         }
@@ -884,6 +933,7 @@ impl<BS: Blockstore> Runtime for MockRuntime<BS> {
         if self.in_transaction {
             return Err(actor_error!(assertion_failed; "nested transaction"));
         }
+        self.require_not_read_only("transaction")?;
         let mut read_only = self.state()?;
         self.in_transaction = true;
         let ret = f(&mut read_only, self);
@@ -909,6 +959,9 @@ impl<BS: Blockstore> Runtime for MockRuntime<BS> {
         if self.in_transaction {
             return Err(actor_error!(assertion_failed; "side-effect within transaction"));
         }
+        if !value.is_zero() {
+            self.require_not_read_only("send with value")?;
+        }
 
         assert!(
             !self.expectations.borrow_mut().expect_sends.is_empty(),
@@ -963,11 +1016,17 @@ impl<BS: Blockstore> Runtime for MockRuntime<BS> {
         Ok(ret)
     }
 
-    fn create_actor(&mut self, code_id: Cid, actor_id: ActorID) -> Result<(), ActorError> {
+    fn create_actor(
+        &mut self,
+        code_id: Cid,
+        actor_id: ActorID,
+        predictable_address: Option<Address>,
+    ) -> Result<(), ActorError> {
         self.require_in_call();
         if self.in_transaction {
             return Err(actor_error!(assertion_failed; "side-effect within transaction"));
         }
+        self.require_not_read_only("create_actor")?;
         let expect_create_actor = self
             .expectations
             .borrow_mut()
@@ -976,6 +1035,11 @@ impl<BS: Blockstore> Runtime for MockRuntime<BS> {
             .expect("unexpected call to create actor");
 
         assert!(expect_create_actor.code_id == code_id && expect_create_actor.actor_id == actor_id, "unexpected actor being created, expected code: {:?} address: {:?}, actual code: {:?} address: {:?}", expect_create_actor.code_id, expect_create_actor.actor_id, code_id, actor_id);
+        assert_eq!(
+            expect_create_actor.predictable_address, predictable_address,
+            "unexpected predictable address for actor {}",
+            actor_id
+        );
         Ok(())
     }
 
@@ -984,6 +1048,7 @@ impl<BS: Blockstore> Runtime for MockRuntime<BS> {
         if self.in_transaction {
             return Err(actor_error!(assertion_failed; "side-effect within transaction"));
         }
+        self.require_not_read_only("delete_actor")?;
         let exp_act = self.expectations.borrow_mut().expect_delete_actor.take();
         if exp_act.is_none() {
             panic!("unexpected call to delete actor: {}", addr);
@@ -1022,6 +1087,10 @@ impl<BS: Blockstore> Runtime for MockRuntime<BS> {
     fn base_fee(&self) -> TokenAmount {
         self.base_fee.clone()
     }
+
+    fn read_only(&self) -> bool {
+        self.read_only
+    }
 }
 
 impl<BS> Primitives for MockRuntime<BS> {
@@ -1069,6 +1138,10 @@ impl<BS> Primitives for MockRuntime<BS> {
     fn hash_blake2b(&self, data: &[u8]) -> [u8; 32] {
         (*self.hash_func)(data)
     }
+
+    fn hash(&self, hasher: SupportedHashes, data: &[u8]) -> Vec<u8> {
+        hash(hasher, data)
+    }
     fn compute_unsealed_sector_cid(
         &self,
         reg: RegisteredSealProof,
@@ -1254,6 +1327,36 @@ pub fn blake2b_256(data: &[u8]) -> [u8; 32] {
         .unwrap()
 }
 
+/// Hashes data with the specified hash function, as the FVM hash syscall would.
+pub fn hash(hasher: SupportedHashes, data: &[u8]) -> Vec<u8> {
+    use multihash::Hasher;
+    match hasher {
+        SupportedHashes::Sha2_256 => {
+            let mut h = multihash::Sha2_256::default();
+            h.update(data);
+            h.finalize().to_vec()
+        }
+        SupportedHashes::Blake2b256 => blake2b_256(data).to_vec(),
+        SupportedHashes::Blake2b512 => blake2b_simd::Params::new()
+            .hash_length(64)
+            .to_state()
+            .update(data)
+            .finalize()
+            .as_bytes()
+            .to_vec(),
+        SupportedHashes::Keccak256 => {
+            let mut h = multihash::Keccak256::default();
+            h.update(data);
+            h.finalize().to_vec()
+        }
+        SupportedHashes::Ripemd160 => {
+            let mut h = multihash::Ripemd160::default();
+            h.update(data);
+            h.finalize().to_vec()
+        }
+    }
+}
+
 // multihash library doesn't support poseidon hashing, so we fake it
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Multihash)]
 #[mh(alloc_size = 64)]
@@ -0,0 +1,262 @@
+// Copyright 2019-2022 ChainSafe Systems
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Minimal Solidity ABI encoding, for native actors building calldata for (or decoding return
+//! data from) Solidity contracts.
+//! Only the types needed for simple calls are supported: fixed-size arrays are not.
+
+use fvm_shared::crypto::hash::SupportedHashes;
+
+use crate::runtime::Primitives;
+use crate::{actor_error, ActorError};
+
+/// The size of an ABI word in bytes.
+pub const WORD_SIZE: usize = 32;
+
+/// A single 32-byte ABI word.
+pub type Word = [u8; WORD_SIZE];
+
+/// An ABI-encodable value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    /// An unsigned integer of up to 256 bits, big-endian.
+    Uint(Word),
+    /// A 20-byte Ethereum address.
+    Address([u8; 20]),
+    Bool(bool),
+    /// A `bytesN` value, with N from 1 to 32.
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    String(String),
+    /// A dynamically sized array `T[]`, all of whose elements must have the same type.
+    Array(Vec<Token>),
+    Tuple(Vec<Token>),
+}
+
+impl Token {
+    /// Builds an unsigned integer token from a native integer.
+    pub fn uint(v: u128) -> Self {
+        let mut word = [0u8; WORD_SIZE];
+        word[16..].copy_from_slice(&v.to_be_bytes());
+        Token::Uint(word)
+    }
+
+    fn is_dynamic(&self) -> bool {
+        match self {
+            Token::Bytes(_) | Token::String(_) | Token::Array(_) => true,
+            Token::Tuple(tokens) => tokens.iter().any(Token::is_dynamic),
+            _ => false,
+        }
+    }
+}
+
+/// The type of an ABI value, describing how to decode it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParamType {
+    Uint,
+    Address,
+    Bool,
+    FixedBytes(usize),
+    Bytes,
+    String,
+    Array(Box<ParamType>),
+    Tuple(Vec<ParamType>),
+}
+
+impl ParamType {
+    fn is_dynamic(&self) -> bool {
+        match self {
+            ParamType::Bytes | ParamType::String | ParamType::Array(_) => true,
+            ParamType::Tuple(types) => types.iter().any(ParamType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// The size of the value's encoding in the head of an enclosing tuple.
+    fn head_size(&self) -> usize {
+        match self {
+            ParamType::Tuple(types) if !self.is_dynamic() => {
+                types.iter().map(ParamType::head_size).sum()
+            }
+            _ => WORD_SIZE,
+        }
+    }
+}
+
+/// Computes the 4-byte selector of a function from its canonical signature,
+/// e.g. `transfer(address,uint256)`.
+pub fn selector(rt: &impl Primitives, signature: &str) -> [u8; 4] {
+    let digest = rt.hash(SupportedHashes::Keccak256, signature.as_bytes());
+    let mut sel = [0u8; 4];
+    sel.copy_from_slice(&digest[..4]);
+    sel
+}
+
+/// Encodes a function call: the selector followed by the arguments encoded as a tuple.
+pub fn encode_call(selector: [u8; 4], args: &[Token]) -> Result<Vec<u8>, ActorError> {
+    let mut out = selector.to_vec();
+    out.extend(encode(args)?);
+    Ok(out)
+}
+
+/// Encodes a sequence of values as the ABI encoding of a tuple of those values.
+/// Fails if a `FixedBytes` token is not between 1 and 32 bytes long.
+pub fn encode(tokens: &[Token]) -> Result<Vec<u8>, ActorError> {
+    let head_size: usize =
+        tokens.iter().map(|t| if t.is_dynamic() { WORD_SIZE } else { static_size(t) }).sum();
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = Vec::new();
+    for token in tokens {
+        if token.is_dynamic() {
+            head.extend_from_slice(&usize_word(head_size + tail.len()));
+            tail.extend(encode_token(token)?);
+        } else {
+            head.extend(encode_token(token)?);
+        }
+    }
+    head.extend(tail);
+    Ok(head)
+}
+
+/// Decodes the ABI encoding of a tuple of values of the given types.
+pub fn decode(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, ActorError> {
+    let mut offset = 0;
+    let mut tokens = Vec::with_capacity(types.len());
+    for typ in types {
+        let token = if typ.is_dynamic() {
+            let start = read_usize(data, offset)?;
+            decode_token(typ, data.get(start..).ok_or_else(|| out_of_bounds(start))?)?
+        } else {
+            decode_token(typ, &data[offset.min(data.len())..])?
+        };
+        tokens.push(token);
+        offset += typ.head_size();
+    }
+    Ok(tokens)
+}
+
+fn static_size(token: &Token) -> usize {
+    match token {
+        Token::Tuple(tokens) => tokens.iter().map(static_size).sum(),
+        _ => WORD_SIZE,
+    }
+}
+
+fn encode_token(token: &Token) -> Result<Vec<u8>, ActorError> {
+    Ok(match token {
+        Token::Uint(word) => word.to_vec(),
+        Token::Address(addr) => {
+            let mut word = [0u8; WORD_SIZE];
+            word[WORD_SIZE - addr.len()..].copy_from_slice(addr);
+            word.to_vec()
+        }
+        Token::Bool(b) => usize_word(*b as usize).to_vec(),
+        Token::FixedBytes(bytes) => {
+            if bytes.is_empty() || bytes.len() > WORD_SIZE {
+                return Err(actor_error!(serialization, "invalid abi bytes{}", bytes.len()));
+            }
+            pad_right(bytes)
+        }
+        Token::Bytes(bytes) => encode_bytes(bytes),
+        Token::String(s) => encode_bytes(s.as_bytes()),
+        Token::Array(tokens) => {
+            let mut out = usize_word(tokens.len()).to_vec();
+            out.extend(encode(tokens)?);
+            out
+        }
+        Token::Tuple(tokens) => encode(tokens)?,
+    })
+}
+
+fn decode_token(typ: &ParamType, data: &[u8]) -> Result<Token, ActorError> {
+    Ok(match typ {
+        ParamType::Uint => Token::Uint(read_word(data, 0)?),
+        ParamType::Address => {
+            let word = read_word(data, 0)?;
+            if word[..12].iter().any(|b| *b != 0) {
+                return Err(actor_error!(serialization, "invalid abi address padding"));
+            }
+            let mut addr = [0u8; 20];
+            addr.copy_from_slice(&word[12..]);
+            Token::Address(addr)
+        }
+        ParamType::Bool => match read_usize(data, 0)? {
+            0 => Token::Bool(false),
+            1 => Token::Bool(true),
+            v => return Err(actor_error!(serialization, "invalid abi bool {}", v)),
+        },
+        ParamType::FixedBytes(len) => {
+            if *len == 0 || *len > WORD_SIZE {
+                return Err(actor_error!(serialization, "invalid abi bytes{}", len));
+            }
+            let word = read_word(data, 0)?;
+            if word[*len..].iter().any(|b| *b != 0) {
+                return Err(actor_error!(serialization, "invalid abi bytes{} padding", len));
+            }
+            Token::FixedBytes(word[..*len].to_vec())
+        }
+        ParamType::Bytes => Token::Bytes(decode_bytes(data)?),
+        ParamType::String => Token::String(
+            String::from_utf8(decode_bytes(data)?)
+                .map_err(|e| actor_error!(serialization, "invalid abi string: {}", e))?,
+        ),
+        ParamType::Array(elem) => {
+            let len = read_usize(data, 0)?;
+            // Each element takes at least one word, which bounds the allocation below.
+            if len > data.len() / WORD_SIZE {
+                return Err(actor_error!(serialization, "abi array length {} too large", len));
+            }
+            let types = vec![(**elem).clone(); len];
+            Token::Array(decode(&types, &data[WORD_SIZE..])?)
+        }
+        ParamType::Tuple(types) => Token::Tuple(decode(types, data)?),
+    })
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut out = usize_word(bytes.len()).to_vec();
+    out.extend(pad_right(bytes));
+    out
+}
+
+fn decode_bytes(data: &[u8]) -> Result<Vec<u8>, ActorError> {
+    let len = read_usize(data, 0)?;
+    let end = WORD_SIZE.checked_add(len).ok_or_else(|| out_of_bounds(len))?;
+    data.get(WORD_SIZE..end).map(<[u8]>::to_vec).ok_or_else(|| out_of_bounds(end))
+}
+
+/// Pads bytes with zeros on the right to a multiple of the word size.
+fn pad_right(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    out.resize((bytes.len() + WORD_SIZE - 1) / WORD_SIZE * WORD_SIZE, 0);
+    out
+}
+
+fn usize_word(v: usize) -> Word {
+    let mut word = [0u8; WORD_SIZE];
+    word[WORD_SIZE - 8..].copy_from_slice(&(v as u64).to_be_bytes());
+    word
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<Word, ActorError> {
+    let mut word = [0u8; WORD_SIZE];
+    let end = offset.checked_add(WORD_SIZE).ok_or_else(|| out_of_bounds(offset))?;
+    word.copy_from_slice(data.get(offset..end).ok_or_else(|| out_of_bounds(end))?);
+    Ok(word)
+}
+
+/// Reads a word which must fit in a (64-bit) usize, such as a length or offset.
+fn read_usize(data: &[u8], offset: usize) -> Result<usize, ActorError> {
+    let word = read_word(data, offset)?;
+    if word[..WORD_SIZE - 8].iter().any(|b| *b != 0) {
+        return Err(actor_error!(serialization, "abi value at {} out of range", offset));
+    }
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&word[WORD_SIZE - 8..]);
+    usize::try_from(u64::from_be_bytes(bytes))
+        .map_err(|_| actor_error!(serialization, "abi value at {} out of range", offset))
+}
+
+fn out_of_bounds(offset: usize) -> ActorError {
+    actor_error!(serialization, "abi data too short for offset {}", offset)
+}
@@ -0,0 +1,84 @@
+// Copyright 2019-2022 ChainSafe Systems
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Batched operations on the maps and arrays which make up actor state.
+//!
+//! Each operation applies many items to a collection which the caller has loaded once,
+//! and which the caller flushes once afterwards, rather than loading and flushing per item.
+
+use fvm_ipld_amt::Error as AmtError;
+use fvm_ipld_blockstore::Blockstore;
+use fvm_ipld_hamt::Error as HamtError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{Array, Keyer, Map};
+
+/// Gets the values of many keys from a map, in the order of the keys.
+pub fn get_many<'m, BS, K, V>(
+    map: &'m Map<'_, BS, V>,
+    keys: &[K],
+) -> Result<Vec<Option<&'m V>>, HamtError>
+where
+    BS: Blockstore,
+    K: Keyer,
+    V: DeserializeOwned + Serialize,
+{
+    keys.iter().map(|k| map.get(&k.key())).collect()
+}
+
+/// Sets many entries in a map, none of which may already be present.
+/// Returns the first key which was already present, in which case later entries are not set.
+pub fn set_many_if_absent<BS, K, V, I>(
+    map: &mut Map<'_, BS, V>,
+    items: I,
+) -> Result<Option<K>, HamtError>
+where
+    BS: Blockstore,
+    K: Keyer,
+    V: DeserializeOwned + Serialize + PartialEq,
+    I: IntoIterator<Item = (K, V)>,
+{
+    for (k, v) in items {
+        if !map.set_if_absent(k.key(), v)? {
+            return Ok(Some(k));
+        }
+    }
+    Ok(None)
+}
+
+/// Deletes many keys from a map, all of which must be present.
+/// Returns the first key which was not present, in which case later keys are not deleted.
+pub fn delete_many<BS, K, V, I>(map: &mut Map<'_, BS, V>, keys: I) -> Result<Option<K>, HamtError>
+where
+    BS: Blockstore,
+    K: Keyer,
+    V: DeserializeOwned + Serialize,
+    I: IntoIterator<Item = K>,
+{
+    for k in keys {
+        if map.delete(&k.key())?.is_none() {
+            return Ok(Some(k));
+        }
+    }
+    Ok(None)
+}
+
+/// Sets many entries in an array.
+/// Entries are set in index order, so that each node of the array is visited in a single pass;
+/// where an index appears more than once, the last value given for it is kept.
+pub fn set_many<BS, V>(
+    array: &mut Array<'_, V, BS>,
+    mut items: Vec<(u64, V)>,
+) -> Result<(), AmtError>
+where
+    BS: Blockstore,
+    V: DeserializeOwned + Serialize,
+{
+    // A stable sort keeps duplicate indices in the order given.
+    items.sort_by_key(|(i, _)| *i);
+    for (i, v) in items {
+        array.set(i, v)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+// Copyright 2019-2022 ChainSafe Systems
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Validation of bitfields supplied in method parameters.
+//!
+//! A small encoded bitfield can describe a very large number of runs, and processing cost is
+//! proportional to the runs, so callers charge for them and bound them before doing any work.
+
+use fvm_ipld_bitfield::BitField;
+
+use crate::runtime::Runtime;
+use crate::{actor_error, ActorError};
+
+/// Gas charged for each run of set bits in a validated bitfield.
+pub const GAS_PER_BITFIELD_RUN: i64 = 1_000;
+
+/// Returns the gas charged to validate a bitfield.
+pub fn bitfield_validation_gas(bf: &BitField) -> i64 {
+    GAS_PER_BITFIELD_RUN.saturating_mul(bf.ranges().count() as i64)
+}
+
+/// Charges gas proportional to the runs in a bitfield, then checks that it has at most
+/// `max_runs` runs and no bit set above `max_value`.
+/// Returns the number of set bits.
+pub fn validate_bitfield(
+    rt: &mut impl Runtime,
+    bf: &BitField,
+    max_runs: u64,
+    max_value: u64,
+    name: &str,
+) -> Result<u64, ActorError> {
+    let runs = bf.ranges().count() as u64;
+    if runs > 0 {
+        rt.charge_gas("OnValidateBitField", GAS_PER_BITFIELD_RUN.saturating_mul(runs as i64));
+    }
+    if runs > max_runs {
+        return Err(actor_error!(
+            illegal_argument,
+            "{} bitfield has too many runs {}, limit {}",
+            name,
+            runs,
+            max_runs
+        ));
+    }
+    if let Some(last) = bf.last() {
+        if last > max_value {
+            return Err(actor_error!(
+                illegal_argument,
+                "{} bitfield value {} exceeds limit {}",
+                name,
+                last,
+                max_value
+            ));
+        }
+    }
+    Ok(bf.len())
+}
@@ -106,7 +106,7 @@ impl Actor {
 
         let actor_address = arg.actor_id;
 
-        rt.create_actor(actor_cid, actor_address)
+        rt.create_actor(actor_cid, actor_address, None)
     }
 
     /// Resolves address, and returns the resolved address (defaulting to 0 ID) and success boolean.
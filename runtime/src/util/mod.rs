@@ -11,7 +11,10 @@ pub use self::multimap::*;
 pub use self::set::Set;
 pub use self::set_multimap::SetMultimap;
 
+pub mod abi;
+pub mod batch_ops;
 mod batch_return;
+pub mod bitfield;
 pub mod cbor;
 pub mod chaos;
 mod downcast;
@@ -0,0 +1,118 @@
+// Copyright 2019-2022 ChainSafe Systems
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+use fil_actors_runtime::abi::{decode, encode, encode_call, selector, ParamType, Token};
+use fil_actors_runtime::test_utils::MockRuntime;
+use fvm_shared::error::ExitCode;
+
+fn words(ws: &[&str]) -> Vec<u8> {
+    ws.iter().flat_map(|w| hex::decode(w).unwrap()).collect()
+}
+
+#[test]
+fn selector_of_signature() {
+    let rt = MockRuntime::default();
+    assert_eq!([0xa9, 0x05, 0x9c, 0xbb], selector(&rt, "transfer(address,uint256)"));
+}
+
+#[test]
+fn static_args() {
+    let args = [Token::uint(69), Token::Bool(true)];
+    let encoded = encode_call([0xcd, 0xcd, 0x77, 0xc0], &args).unwrap();
+    let expected = words(&[
+        "cdcd77c0",
+        "0000000000000000000000000000000000000000000000000000000000000045",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+    ]);
+    assert_eq!(expected, encoded);
+    assert_eq!(args.to_vec(), decode(&[ParamType::Uint, ParamType::Bool], &encoded[4..]).unwrap());
+}
+
+#[test]
+fn dynamic_args() {
+    // f(uint256,uint32[],bytes10,bytes), the example from the Solidity ABI specification.
+    let args = [
+        Token::uint(0x123),
+        Token::Array(vec![Token::uint(0x456), Token::uint(0x789)]),
+        Token::FixedBytes(b"1234567890".to_vec()),
+        Token::Bytes(b"Hello, world!".to_vec()),
+    ];
+    let expected = words(&[
+        "0000000000000000000000000000000000000000000000000000000000000123",
+        "0000000000000000000000000000000000000000000000000000000000000080",
+        "3132333435363738393000000000000000000000000000000000000000000000",
+        "00000000000000000000000000000000000000000000000000000000000000e0",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000456",
+        "0000000000000000000000000000000000000000000000000000000000000789",
+        "000000000000000000000000000000000000000000000000000000000000000d",
+        "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+    ]);
+    assert_eq!(expected, encode(&args).unwrap());
+
+    let types = [
+        ParamType::Uint,
+        ParamType::Array(Box::new(ParamType::Uint)),
+        ParamType::FixedBytes(10),
+        ParamType::Bytes,
+    ];
+    assert_eq!(args.to_vec(), decode(&types, &expected).unwrap());
+}
+
+#[test]
+fn tuple_and_string_round_trip() {
+    let args = [
+        Token::Tuple(vec![Token::Address([7u8; 20]), Token::uint(1)]),
+        Token::Tuple(vec![Token::String("deal".to_owned()), Token::Bool(false)]),
+    ];
+    let types = [
+        ParamType::Tuple(vec![ParamType::Address, ParamType::Uint]),
+        ParamType::Tuple(vec![ParamType::String, ParamType::Bool]),
+    ];
+    let encoded = encode(&args).unwrap();
+    // The static tuple is inlined in the head, the dynamic one is referenced by offset.
+    assert_eq!(3 * 32, u64::from_be_bytes(encoded[88..96].try_into().unwrap()) as usize);
+    assert_eq!(args.to_vec(), decode(&types, &encoded).unwrap());
+}
+
+#[test]
+fn decode_rejects_malformed_data() {
+    let truncated = words(&["0000000000000000000000000000000000000000000000000000000000000045"]);
+    let err = decode(&[ParamType::Uint, ParamType::Uint], &truncated).unwrap_err();
+    assert_eq!(ExitCode::USR_SERIALIZATION, err.exit_code());
+
+    let bad_address = words(&["0100000000000000000000000000000000000000000000000000000000000045"]);
+    let err = decode(&[ParamType::Address], &bad_address).unwrap_err();
+    assert_eq!(ExitCode::USR_SERIALIZATION, err.exit_code());
+
+    // A bytes length running past the end of the data.
+    let long_bytes = words(&[
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "00000000000000000000000000000000000000000000000000000000000000ff",
+    ]);
+    let err = decode(&[ParamType::Bytes], &long_bytes).unwrap_err();
+    assert_eq!(ExitCode::USR_SERIALIZATION, err.exit_code());
+}
+
+#[test]
+fn encode_rejects_invalid_fixed_bytes() {
+    for len in [0, 33] {
+        let err = encode(&[Token::FixedBytes(vec![1; len])]).unwrap_err();
+        assert_eq!(ExitCode::USR_SERIALIZATION, err.exit_code());
+    }
+    let encoded = encode(&[Token::FixedBytes(vec![1; 32])]).unwrap();
+    assert_eq!(vec![1u8; 32], encoded);
+}
+
+#[test]
+fn decode_rejects_fixed_bytes_padding() {
+    let padded = words(&["3132000000000000000000000000000000000000000000000000000000000000"]);
+    assert_eq!(
+        vec![Token::FixedBytes(b"12".to_vec())],
+        decode(&[ParamType::FixedBytes(2)], &padded).unwrap()
+    );
+
+    let dirty = words(&["3132330000000000000000000000000000000000000000000000000000000000"]);
+    let err = decode(&[ParamType::FixedBytes(2)], &dirty).unwrap_err();
+    assert_eq!(ExitCode::USR_SERIALIZATION, err.exit_code());
+}
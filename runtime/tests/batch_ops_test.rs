@@ -0,0 +1,59 @@
+// Copyright 2019-2022 ChainSafe Systems
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+use fil_actors_runtime::batch_ops::{delete_many, get_many, set_many, set_many_if_absent};
+use fil_actors_runtime::{make_empty_map, Array};
+use fvm_ipld_blockstore::MemoryBlockstore;
+use fvm_shared::HAMT_BIT_WIDTH;
+
+#[test]
+fn map_set_get_delete_many() {
+    let store = MemoryBlockstore::new();
+    let mut map = make_empty_map::<_, String>(&store, HAMT_BIT_WIDTH);
+
+    let entries = vec![(3u64, "c".to_owned()), (1, "a".to_owned()), (2, "b".to_owned())];
+    assert_eq!(None, set_many_if_absent(&mut map, entries).unwrap());
+    let values = get_many(&map, &[1u64, 4, 3]).unwrap();
+    assert_eq!(vec![Some(&"a".to_owned()), None, Some(&"c".to_owned())], values);
+
+    // Setting a present key reports it, leaving the existing value.
+    let entries = vec![(4u64, "d".to_owned()), (2, "x".to_owned()), (5, "e".to_owned())];
+    assert_eq!(Some(2), set_many_if_absent(&mut map, entries).unwrap());
+    assert_eq!(
+        vec![Some(&"d".to_owned()), Some(&"b".to_owned())],
+        get_many(&map, &[4u64, 2]).unwrap()
+    );
+
+    // Deleting a missing key reports it.
+    assert_eq!(None, delete_many(&mut map, vec![1u64, 3]).unwrap());
+    assert_eq!(Some(1), delete_many(&mut map, vec![2u64, 1]).unwrap());
+    assert_eq!(
+        vec![None, None, None, Some(&"d".to_owned())],
+        get_many(&map, &[1u64, 2, 3, 4]).unwrap()
+    );
+}
+
+#[test]
+fn map_batch_matches_individual_operations() {
+    let store = MemoryBlockstore::new();
+    let mut batched = make_empty_map::<_, u64>(&store, HAMT_BIT_WIDTH);
+    let mut single = make_empty_map::<_, u64>(&store, HAMT_BIT_WIDTH);
+
+    set_many_if_absent(&mut batched, (0..100u64).rev().map(|i| (i, i * 2))).unwrap();
+    for i in 0..100u64 {
+        single.set_if_absent(fil_actors_runtime::u64_key(i), i * 2).unwrap();
+    }
+    assert_eq!(single.flush().unwrap(), batched.flush().unwrap());
+}
+
+#[test]
+fn array_set_many_keeps_last_duplicate() {
+    let store = MemoryBlockstore::new();
+    let mut array = Array::<u64, _>::new(&store);
+
+    set_many(&mut array, vec![(9, 90), (2, 20), (9, 91), (0, 0)]).unwrap();
+    assert_eq!(Some(&0), array.get(0).unwrap());
+    assert_eq!(Some(&20), array.get(2).unwrap());
+    assert_eq!(Some(&91), array.get(9).unwrap());
+    assert_eq!(3, array.count());
+}
@@ -0,0 +1,59 @@
+// Copyright 2019-2022 ChainSafe Systems
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+use fil_actors_runtime::bitfield::{
+    bitfield_validation_gas, validate_bitfield, GAS_PER_BITFIELD_RUN,
+};
+use fil_actors_runtime::test_utils::MockRuntime;
+use fvm_ipld_bitfield::BitField;
+use fvm_shared::error::ExitCode;
+
+#[test]
+fn empty_bitfield_charges_no_gas() {
+    let mut rt = MockRuntime::default();
+    let bf = BitField::new();
+    assert_eq!(0, bitfield_validation_gas(&bf));
+
+    // No gas charge is expected, so the mock runtime fails on any charge.
+    assert_eq!(0, validate_bitfield(&mut rt, &bf, 0, 0, "empty").unwrap());
+    rt.verify();
+}
+
+#[test]
+fn charges_per_run_and_counts_bits() {
+    let mut rt = MockRuntime::default();
+    let bf = BitField::try_from_bits([1, 2, 3, 7, 10, 11]).unwrap();
+    assert_eq!(3 * GAS_PER_BITFIELD_RUN, bitfield_validation_gas(&bf));
+
+    rt.expect_gas_charge(3 * GAS_PER_BITFIELD_RUN);
+    assert_eq!(6, validate_bitfield(&mut rt, &bf, 3, 11, "bits").unwrap());
+    rt.verify();
+}
+
+#[test]
+fn rejects_too_many_runs() {
+    let mut rt = MockRuntime::default();
+    let bf = BitField::try_from_bits([1, 3, 5]).unwrap();
+
+    // Gas is charged for the runs before they are rejected.
+    rt.expect_gas_charge(3 * GAS_PER_BITFIELD_RUN);
+    let err = validate_bitfield(&mut rt, &bf, 2, 100, "runs").unwrap_err();
+    assert_eq!(ExitCode::USR_ILLEGAL_ARGUMENT, err.exit_code());
+    rt.verify();
+}
+
+#[test]
+fn rejects_value_above_max() {
+    let mut rt = MockRuntime::default();
+    let bf = BitField::try_from_bits([0, 1, 2, 9]).unwrap();
+
+    rt.expect_gas_charge(2 * GAS_PER_BITFIELD_RUN);
+    let err = validate_bitfield(&mut rt, &bf, 10, 8, "values").unwrap_err();
+    assert_eq!(ExitCode::USR_ILLEGAL_ARGUMENT, err.exit_code());
+    rt.verify();
+
+    // The maximum value itself is allowed.
+    rt.expect_gas_charge(2 * GAS_PER_BITFIELD_RUN);
+    assert_eq!(4, validate_bitfield(&mut rt, &bf, 10, 9, "values").unwrap());
+    rt.verify();
+}
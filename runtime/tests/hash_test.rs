@@ -0,0 +1,71 @@
+// Copyright 2019-2022 ChainSafe Systems
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+use fil_actors_runtime::runtime::Primitives;
+use fil_actors_runtime::test_utils::MockRuntime;
+use fvm_shared::crypto::hash::SupportedHashes;
+
+const INPUT: &[u8] = b"abc";
+
+// Published digests of "abc" for each supported hash function.
+const KNOWN_ANSWERS: &[(SupportedHashes, &str)] = &[
+    (SupportedHashes::Sha2_256, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+    (
+        SupportedHashes::Blake2b256,
+        "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+    ),
+    (
+        SupportedHashes::Blake2b512,
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+         7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+    ),
+    (
+        SupportedHashes::Keccak256,
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+    ),
+    (SupportedHashes::Ripemd160, "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+];
+
+#[test]
+fn hash_matches_known_answers() {
+    let rt = MockRuntime::default();
+    for (hasher, expected) in KNOWN_ANSWERS {
+        assert_eq!(*expected, hex::encode(rt.hash(*hasher, INPUT)), "{:?}", hasher);
+    }
+}
+
+#[test]
+fn hash_blake2b_matches_blake2b256() {
+    let rt = MockRuntime::default();
+    assert_eq!(rt.hash(SupportedHashes::Blake2b256, INPUT), rt.hash_blake2b(INPUT).to_vec());
+}
+
+#[test]
+fn hash_into_truncates_to_short_buffer() {
+    let rt = MockRuntime::default();
+    for (hasher, _) in KNOWN_ANSWERS {
+        let full = rt.hash(*hasher, INPUT);
+        let mut digest = [0u8; 16];
+        assert_eq!(16, rt.hash_into(*hasher, INPUT, &mut digest), "{:?}", hasher);
+        assert_eq!(full[..16], digest, "{:?}", hasher);
+    }
+}
+
+#[test]
+fn hash_into_leaves_excess_buffer_untouched() {
+    let rt = MockRuntime::default();
+    for (hasher, _) in KNOWN_ANSWERS {
+        let full = rt.hash(*hasher, INPUT);
+        let mut digest = [0xffu8; 80];
+        assert_eq!(full.len(), rt.hash_into(*hasher, INPUT, &mut digest), "{:?}", hasher);
+        assert_eq!(full, digest[..full.len()], "{:?}", hasher);
+        assert!(digest[full.len()..].iter().all(|b| *b == 0xff), "{:?}", hasher);
+    }
+}
+
+#[test]
+fn hash_into_empty_buffer_writes_nothing() {
+    let rt = MockRuntime::default();
+    let mut digest = [0u8; 0];
+    assert_eq!(0, rt.hash_into(SupportedHashes::Sha2_256, INPUT, &mut digest));
+}
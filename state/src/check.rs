@@ -199,7 +199,7 @@ pub fn check_state_invariants<'a, BS: Blockstore + Debug>(
             Some(Type::Reward) => {
                 let state = get_state!(tree, actor, RewardState);
                 let (summary, msgs) =
-                    reward::check_state_invariants(&state, prior_epoch, &actor.balance);
+                    reward::check_state_invariants(policy, &state, prior_epoch, &actor.balance);
                 acc.with_prefix("reward: ").add_all(&msgs);
                 reward_summary = Some(summary);
             }
@@ -1 +1,3 @@
 pub mod check;
+pub mod manifest;
+pub mod migration;
@@ -0,0 +1,128 @@
+//! Loading and validation of the builtin actors bundle manifest.
+//!
+//! A bundle is a CAR whose root is the manifest: a `(version, data)` tuple, where `data` links to
+//! a list of `(name, code)` pairs naming the code CID of each actor in the bundle. The CAR is
+//! imported into a blockstore and the manifest loaded from its root, so that node implementations
+//! and test harnesses resolve actor code through the same path.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Context};
+use bimap::BiBTreeMap;
+use cid::Cid;
+use fil_actors_runtime::runtime::builtins::Type;
+use futures::executor::block_on;
+use futures::AsyncRead;
+use fvm_ipld_blockstore::Blockstore;
+use fvm_ipld_encoding::CborStore;
+use num_traits::FromPrimitive;
+
+/// The multihash code for the identity hash, whose digest is the data itself.
+const IDENTITY_HASH: u64 = 0;
+
+/// The manifest of a builtin actors bundle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    by_name: BTreeMap<String, Cid>,
+    by_code: BiBTreeMap<Cid, Type>,
+}
+
+impl Manifest {
+    /// The only manifest version understood by this library.
+    pub const VERSION: u32 = 1;
+
+    /// Loads the manifest rooted at `root` from a store into which the bundle has been imported.
+    pub fn load<BS: Blockstore>(store: &BS, root: &Cid) -> anyhow::Result<Self> {
+        let (version, data): (u32, Cid) = store
+            .get_cbor(root)
+            .context("failed to load manifest root")?
+            .ok_or_else(|| anyhow!("manifest root {} not found", root))?;
+        if version != Self::VERSION {
+            bail!("unsupported manifest version {}", version);
+        }
+        let entries: Vec<(String, Cid)> = store
+            .get_cbor(&data)
+            .context("failed to load manifest data")?
+            .ok_or_else(|| anyhow!("manifest data {} not found", data))?;
+        Self::from_entries(entries)
+    }
+
+    /// Imports a bundle CAR into `store` and loads the manifest at its root.
+    pub fn load_car<BS, R>(store: &BS, reader: R) -> anyhow::Result<Self>
+    where
+        BS: Blockstore,
+        R: AsyncRead + Send + Unpin,
+    {
+        let roots =
+            block_on(fvm_ipld_car::load_car(store, reader)).context("failed to import bundle")?;
+        match roots.as_slice() {
+            [root] => Self::load(store, root),
+            _ => bail!("bundle must have exactly one root, found {}", roots.len()),
+        }
+    }
+
+    /// Builds a manifest from `(name, code)` pairs, rejecting duplicate names or code CIDs.
+    /// Names which don't refer to a known builtin actor type are kept but not typed.
+    pub fn from_entries(entries: Vec<(String, Cid)>) -> anyhow::Result<Self> {
+        let types: BTreeMap<&str, Type> =
+            (1..).map_while(Type::from_i32).map(|t| (t.name(), t)).collect();
+        let mut manifest = Manifest::default();
+        for (name, code) in entries {
+            if manifest.by_name.values().any(|c| c == &code) {
+                bail!("duplicate code {} for actor {}", code, name);
+            }
+            if let Some(typ) = types.get(name.as_str()) {
+                manifest.by_code.insert(code, *typ);
+            }
+            if manifest.by_name.insert(name.clone(), code).is_some() {
+                bail!("duplicate actor name {}", name);
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Returns the code CID of the actor with the given name.
+    pub fn code_by_name(&self, name: &str) -> Option<&Cid> {
+        self.by_name.get(name)
+    }
+
+    /// Returns the code CID of the given builtin actor type.
+    pub fn code_by_type(&self, typ: Type) -> Option<&Cid> {
+        self.by_code.get_by_right(&typ)
+    }
+
+    /// Returns the builtin actor type of the given code CID.
+    pub fn type_by_code(&self, code: &Cid) -> Option<Type> {
+        self.by_code.get_by_left(code).copied()
+    }
+
+    /// All named actors in the manifest.
+    pub fn actors(&self) -> impl Iterator<Item = (&str, &Cid)> {
+        self.by_name.iter().map(|(name, code)| (name.as_str(), code))
+    }
+
+    /// The mapping of code CIDs to builtin actor types, as expected by state invariant checks.
+    pub fn builtin_actors(&self) -> &BiBTreeMap<Cid, Type> {
+        &self.by_code
+    }
+
+    /// Checks that every builtin actor type is present in the manifest and that the code of
+    /// every actor named in it is available in the store.
+    pub fn validate<BS: Blockstore>(&self, store: &BS) -> anyhow::Result<()> {
+        for typ in (1..).map_while(Type::from_i32) {
+            if self.code_by_type(typ).is_none() {
+                bail!("manifest is missing actor {}", typ.name());
+            }
+        }
+        for (name, code) in self.actors() {
+            // Identity-hashed code is inlined in the CID and has no separate block.
+            if code.hash().code() == IDENTITY_HASH {
+                continue;
+            }
+            if !store.has(code).with_context(|| format!("failed to look up code for {}", name))? {
+                bail!("code {} for actor {} not found in store", code, name);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+//! A framework for migrating the state tree from one version of the builtin actors to the next.
+//!
+//! A migration is described by a set of per-actor migrators, keyed by the code CID of the
+//! actor being migrated. Each migrator receives the prior head of an actor and returns the new
+//! code CID and head. Actors are migrated independently of each other, so the state tree can be
+//! traversed in parallel. Sub-structures which are shared between many actors (or which are
+//! unchanged between several migration runs) can be memoized in the [`MigrationCache`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use cid::Cid;
+use fil_actors_runtime::Map;
+use fvm_ipld_blockstore::Blockstore;
+use fvm_shared::address::Address;
+use fvm_shared::clock::ChainEpoch;
+use fvm_shared::econ::TokenAmount;
+
+use crate::check::{Actor, Tree};
+
+/// The inputs to the migration of a single actor.
+#[derive(Clone, Debug)]
+pub struct ActorMigrationInput {
+    /// The ID address of the actor being migrated.
+    pub address: Address,
+    /// The balance of the actor being migrated.
+    pub balance: TokenAmount,
+    /// The prior state head of the actor.
+    pub head: Cid,
+    /// The epoch of the last tipset before the upgrade.
+    pub prior_epoch: ChainEpoch,
+}
+
+/// The result of migrating a single actor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActorMigrationOutput {
+    /// The code CID of the actor after migration.
+    pub new_code_cid: Cid,
+    /// The state head of the actor after migration.
+    pub new_head: Cid,
+}
+
+/// Migrates the state of all actors of a single code CID.
+pub trait ActorMigration<BS: Blockstore>: Send + Sync {
+    fn migrate_state(
+        &self,
+        store: &BS,
+        input: ActorMigrationInput,
+        cache: &MigrationCache,
+    ) -> anyhow::Result<ActorMigrationOutput>;
+}
+
+/// A migrator which changes only the actor's code CID, leaving the state unchanged.
+/// This is the migration for all actors whose state schema doesn't change in an upgrade.
+pub struct NilMigrator {
+    new_code_cid: Cid,
+}
+
+impl NilMigrator {
+    pub fn new(new_code_cid: Cid) -> Self {
+        Self { new_code_cid }
+    }
+}
+
+impl<BS: Blockstore> ActorMigration<BS> for NilMigrator {
+    fn migrate_state(
+        &self,
+        _store: &BS,
+        input: ActorMigrationInput,
+        _cache: &MigrationCache,
+    ) -> anyhow::Result<ActorMigrationOutput> {
+        Ok(ActorMigrationOutput { new_code_cid: self.new_code_cid, new_head: input.head })
+    }
+}
+
+/// A cache of migrated sub-structures, keyed by a migrator-defined string (conventionally
+/// a name for the structure followed by its prior root CID) and mapping to the migrated root.
+/// Migrations which share a cache between runs (e.g. a pre-migration followed by the real
+/// migration at the upgrade epoch) only pay for structures that changed in between.
+#[derive(Default)]
+pub struct MigrationCache {
+    entries: Mutex<HashMap<String, Cid>>,
+}
+
+impl MigrationCache {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<Cid> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: String, value: Cid) {
+        self.entries.lock().unwrap().insert(key, value);
+    }
+
+    /// Returns the cached value for `key`, computing and caching it with `f` if absent.
+    /// The lock is not held while `f` runs, so concurrent callers may compute the same value
+    /// more than once. Migrations are deterministic so they will agree on the result.
+    pub fn get_or_insert_with<F>(&self, key: String, f: F) -> anyhow::Result<Cid>
+    where
+        F: FnOnce() -> anyhow::Result<Cid>,
+    {
+        if let Some(cid) = self.get(&key) {
+            return Ok(cid);
+        }
+        let cid = f()?;
+        self.insert(key, cid);
+        Ok(cid)
+    }
+}
+
+/// A migration of the whole state tree, consisting of one migrator per prior actor code CID.
+pub struct StateMigration<BS: Blockstore> {
+    migrations: HashMap<Cid, Arc<dyn ActorMigration<BS>>>,
+}
+
+impl<BS: Blockstore> Default for StateMigration<BS> {
+    fn default() -> Self {
+        Self { migrations: HashMap::new() }
+    }
+}
+
+impl<BS: Blockstore> StateMigration<BS> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers the migrator for all actors with code CID `prior_code`.
+    /// Every code CID present in the state tree must have a migrator.
+    pub fn add_migrator(&mut self, prior_code: Cid, migrator: Arc<dyn ActorMigration<BS>>) {
+        self.migrations.insert(prior_code, migrator);
+    }
+
+    /// Migrates every actor in the state tree rooted at `actors_root`,
+    /// returning the root of the new state tree.
+    pub fn migrate_state_tree(
+        &self,
+        store: &BS,
+        actors_root: &Cid,
+        prior_epoch: ChainEpoch,
+        cache: &MigrationCache,
+    ) -> anyhow::Result<Cid> {
+        let jobs = self.collect_jobs(store, actors_root)?;
+        let results = jobs
+            .into_iter()
+            .map(|(address, actor)| self.migrate_actor(store, address, actor, prior_epoch, cache))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        write_tree(store, results)
+    }
+
+    /// Like [`StateMigration::migrate_state_tree`], but migrates actors on `workers` threads.
+    /// The resulting state tree is identical to that of a sequential migration.
+    pub fn migrate_state_tree_parallel(
+        &self,
+        store: &BS,
+        actors_root: &Cid,
+        prior_epoch: ChainEpoch,
+        cache: &MigrationCache,
+        workers: usize,
+    ) -> anyhow::Result<Cid>
+    where
+        BS: Sync,
+    {
+        let jobs = self.collect_jobs(store, actors_root)?;
+        if jobs.is_empty() {
+            return write_tree(store, vec![]);
+        }
+        let chunk_size = (jobs.len() + workers.max(1) - 1) / workers.max(1);
+        let results = std::thread::scope(|s| {
+            let handles: Vec<_> = jobs
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    s.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|(address, actor)| {
+                                self.migrate_actor(
+                                    store,
+                                    *address,
+                                    actor.clone(),
+                                    prior_epoch,
+                                    cache,
+                                )
+                            })
+                            .collect::<anyhow::Result<Vec<_>>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().map_err(|_| anyhow!("migration worker panicked"))?)
+                .collect::<anyhow::Result<Vec<_>>>()
+        })?;
+        write_tree(store, results.into_iter().flatten().collect())
+    }
+
+    fn collect_jobs(&self, store: &BS, actors_root: &Cid) -> anyhow::Result<Vec<(Address, Actor)>> {
+        let tree = Tree::load(store, actors_root)?;
+        let mut jobs = Vec::new();
+        tree.for_each(|address, actor| {
+            if !self.migrations.contains_key(&actor.code) {
+                return Err(anyhow!(
+                    "no migration registered for actor {} with code {}",
+                    address,
+                    actor.code
+                ));
+            }
+            jobs.push((*address, actor.clone()));
+            Ok(())
+        })?;
+        Ok(jobs)
+    }
+
+    fn migrate_actor(
+        &self,
+        store: &BS,
+        address: Address,
+        actor: Actor,
+        prior_epoch: ChainEpoch,
+        cache: &MigrationCache,
+    ) -> anyhow::Result<(Address, Actor)> {
+        // Presence of a migrator for every code was checked when collecting jobs.
+        let migrator = &self.migrations[&actor.code];
+        let input = ActorMigrationInput {
+            address,
+            balance: actor.balance.clone(),
+            head: actor.head,
+            prior_epoch,
+        };
+        let output = migrator
+            .migrate_state(store, input, cache)
+            .map_err(|e| anyhow!("failed to migrate actor {}: {}", address, e))?;
+        Ok((address, Actor { code: output.new_code_cid, head: output.new_head, ..actor }))
+    }
+}
+
+fn write_tree<BS: Blockstore>(store: &BS, actors: Vec<(Address, Actor)>) -> anyhow::Result<Cid> {
+    let mut map: Map<BS, Actor> = Map::new(store);
+    for (address, actor) in actors {
+        map.set(address.to_bytes().into(), actor)?;
+    }
+    Ok(map.flush()?)
+}
@@ -39,6 +39,7 @@ use fvm_shared::address::{Address, Protocol};
 use fvm_shared::bigint::Zero;
 use fvm_shared::clock::ChainEpoch;
 use fvm_shared::consensus::ConsensusFault;
+use fvm_shared::crypto::hash::SupportedHashes;
 use fvm_shared::crypto::signature::Signature;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::ExitCode;
@@ -69,6 +70,7 @@ pub struct VM<'bs> {
     actors_dirty: RefCell<bool>,
     actors_cache: RefCell<HashMap<Address, Actor>>,
     network_version: NetworkVersion,
+    policy: Policy,
     curr_epoch: ChainEpoch,
     invocations: RefCell<Vec<InvocationTrace>>,
 }
@@ -119,6 +121,7 @@ impl<'bs> VM<'bs> {
             actors_dirty: RefCell::new(false),
             actors_cache: RefCell::new(HashMap::new()),
             network_version: NetworkVersion::V16,
+            policy: Policy::default(),
             curr_epoch: ChainEpoch::zero(),
             invocations: RefCell::new(vec![]),
         }
@@ -128,6 +131,26 @@ impl<'bs> VM<'bs> {
         Self { total_fil, ..self }
     }
 
+    /// Returns this VM running at a different network version.
+    /// May be used part way through a test to exercise behaviour across an upgrade.
+    pub fn with_network_version(self, network_version: NetworkVersion) -> Self {
+        Self { network_version, ..self }
+    }
+
+    /// Returns this VM with a policy other than the default, which is presented to all actors
+    /// and used when checking state invariants.
+    pub fn with_policy(self, policy: Policy) -> Self {
+        Self { policy, ..self }
+    }
+
+    pub fn network_version(&self) -> NetworkVersion {
+        self.network_version
+    }
+
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
     pub fn new_with_singletons(store: &'bs MemoryBlockstore) -> VM<'bs> {
         let reward_total = TokenAmount::from_whole(1_100_000_000i64);
         let faucet_total = TokenAmount::from_whole(1_000_000_000i64);
@@ -147,7 +170,7 @@ impl<'bs> VM<'bs> {
 
         // reward
 
-        let reward_head = v.put_store(&RewardState::new(StoragePower::zero()));
+        let reward_head = v.put_store(&RewardState::new(v.policy(), StoragePower::zero()));
         v.set_actor(REWARD_ACTOR_ADDR, actor(*REWARD_ACTOR_CODE_ID, reward_head, 0, reward_total));
 
         // cron
@@ -264,11 +287,36 @@ impl<'bs> VM<'bs> {
             actors_dirty: RefCell::new(false),
             actors_cache: RefCell::new(HashMap::new()),
             network_version: self.network_version,
+            policy: self.policy,
             curr_epoch: epoch,
             invocations: RefCell::new(vec![]),
         }
     }
 
+    /// Returns a new VM sharing this VM's blockstore and starting from its current state.
+    /// Messages applied to the fork don't affect this VM and vice versa, so a test can explore
+    /// alternative executions (e.g. a message failing, or a re-org) from a common starting point.
+    pub fn fork(&self) -> VM<'bs> {
+        let root = self.checkpoint();
+        self.fork_at(root)
+    }
+
+    /// Returns a new VM sharing this VM's blockstore and starting from a state root
+    /// previously returned by `checkpoint`.
+    pub fn fork_at(&self, root: Cid) -> VM<'bs> {
+        VM {
+            store: self.store,
+            state_root: RefCell::new(root),
+            total_fil: self.total_fil.clone(),
+            actors_dirty: RefCell::new(false),
+            actors_cache: RefCell::new(HashMap::new()),
+            network_version: self.network_version,
+            policy: self.policy.clone(),
+            curr_epoch: self.curr_epoch,
+            invocations: RefCell::new(vec![]),
+        }
+    }
+
     pub fn get_miner_balance(&self, maddr: Address) -> MinerBalances {
         let a = self.get_actor(maddr).unwrap();
         let st = self.get_state::<MinerState>(maddr).unwrap();
@@ -342,6 +390,19 @@ impl<'bs> VM<'bs> {
         self.actors_dirty.replace(true);
     }
 
+    // remove the actor at this address from the state tree, if present
+    pub fn delete_actor(&self, key: Address) {
+        self.checkpoint();
+        let mut actors = Hamt::<&'bs MemoryBlockstore, Actor, BytesKey, Sha256>::load(
+            &self.state_root.borrow(),
+            self.store,
+        )
+        .unwrap();
+        actors.delete(&key.to_bytes()).unwrap();
+        self.actors_cache.borrow_mut().remove(&key);
+        self.state_root.replace(actors.flush().unwrap());
+    }
+
     pub fn checkpoint(&self) -> Cid {
         // persist cache on top of latest checkpoint and clear
         let mut actors = Hamt::<&'bs MemoryBlockstore, Actor, BytesKey, Sha256>::load(
@@ -401,6 +462,31 @@ impl<'bs> VM<'bs> {
         value: TokenAmount,
         method: MethodNum,
         params: C,
+    ) -> Result<MessageResult, TestVMError> {
+        self.apply_message_with_mode(from, to, value, method, params, false)
+    }
+
+    /// Applies a message in read-only mode, in which the message and any sends it makes may not
+    /// create or delete actors, modify state or transfer value.
+    pub fn apply_message_read_only<C: Cbor>(
+        &self,
+        from: Address,
+        to: Address,
+        value: TokenAmount,
+        method: MethodNum,
+        params: C,
+    ) -> Result<MessageResult, TestVMError> {
+        self.apply_message_with_mode(from, to, value, method, params, true)
+    }
+
+    fn apply_message_with_mode<C: Cbor>(
+        &self,
+        from: Address,
+        to: Address,
+        value: TokenAmount,
+        method: MethodNum,
+        params: C,
+        read_only: bool,
     ) -> Result<MessageResult, TestVMError> {
         let from_id = self.normalize_address(&from).unwrap();
         let mut a = self.get_actor(from_id).unwrap();
@@ -431,8 +517,9 @@ impl<'bs> VM<'bs> {
             msg,
             allow_side_effects: true,
             caller_validated: false,
-            policy: &Policy::default(),
+            policy: &self.policy,
             subinvocations: RefCell::new(vec![]),
+            read_only,
         };
         let res = new_ctx.invoke();
         let invoc = new_ctx.gather_trace(res.clone());
@@ -477,11 +564,10 @@ impl<'bs> VM<'bs> {
             })
             .unwrap();
 
-        let policy = Policy::default();
         let state_tree = Tree::load(&self.store, &self.state_root.borrow()).unwrap();
         check_state_invariants(
             &manifest,
-            &policy,
+            &self.policy,
             state_tree,
             &self.total_fil,
             self.get_epoch() - 1,
@@ -563,6 +649,7 @@ pub struct InvocationCtx<'invocation, 'bs> {
     caller_validated: bool,
     policy: &'invocation Policy,
     subinvocations: RefCell<Vec<InvocationTrace>>,
+    read_only: bool,
 }
 
 impl<'invocation, 'bs> InvocationCtx<'invocation, 'bs> {
@@ -583,6 +670,7 @@ impl<'invocation, 'bs> InvocationCtx<'invocation, 'bs> {
             }
             _ => (),
         }
+        self.check_not_read_only("create account")?;
         let mut st = self.v.get_state::<InitState>(INIT_ACTOR_ADDR).unwrap();
         let target_id = st.map_address_to_new_id(self.v.store, target).unwrap();
         let target_id_addr = Address::new_id(target_id);
@@ -606,8 +694,9 @@ impl<'invocation, 'bs> InvocationCtx<'invocation, 'bs> {
                 caller_validated: false,
                 policy: self.policy,
                 subinvocations: RefCell::new(vec![]),
+                read_only: self.read_only,
             };
-            new_ctx.create_actor(*ACCOUNT_ACTOR_CODE_ID, target_id).unwrap();
+            new_ctx.create_actor(*ACCOUNT_ACTOR_CODE_ID, target_id, None).unwrap();
             let res = new_ctx.invoke();
             let invoc = new_ctx.gather_trace(res);
             RefMut::map(self.subinvocations.borrow_mut(), |subinvocs| {
@@ -632,6 +721,16 @@ impl<'invocation, 'bs> InvocationCtx<'invocation, 'bs> {
         InvocationTrace { msg, code, ret, subinvocations: self.subinvocations.take() }
     }
 
+    fn check_not_read_only(&self, operation: &str) -> Result<(), ActorError> {
+        if self.read_only {
+            return Err(ActorError::unchecked(
+                ExitCode::USR_FORBIDDEN,
+                format!("{} is not allowed in read-only mode", operation),
+            ));
+        }
+        Ok(())
+    }
+
     fn to(&'_ self) -> Address {
         self.resolve_target(&self.msg.to).unwrap().1
     }
@@ -642,6 +741,7 @@ impl<'invocation, 'bs> InvocationCtx<'invocation, 'bs> {
         // Transfer funds
         let mut from_actor = self.v.get_actor(self.msg.from).unwrap();
         if !self.msg.value.is_zero() {
+            self.check_not_read_only("send with value")?;
             if self.msg.value.is_negative() {
                 return Err(ActorError::unchecked(
                     ExitCode::SYS_ASSERTION_FAILED,
@@ -702,7 +802,19 @@ impl<'invocation, 'bs> InvocationCtx<'invocation, 'bs> {
 impl<'invocation, 'bs> Runtime for InvocationCtx<'invocation, 'bs> {
     type Blockstore = &'bs MemoryBlockstore;
 
-    fn create_actor(&mut self, code_id: Cid, actor_id: ActorID) -> Result<(), ActorError> {
+    fn create_actor(
+        &mut self,
+        code_id: Cid,
+        actor_id: ActorID,
+        predictable_address: Option<Address>,
+    ) -> Result<(), ActorError> {
+        self.check_not_read_only("create_actor")?;
+        if predictable_address.is_some() {
+            return Err(ActorError::unchecked(
+                ExitCode::USR_ILLEGAL_ARGUMENT,
+                "delegated addresses are not supported".to_string(),
+            ));
+        }
         match NON_SINGLETON_CODES.get(&code_id) {
             Some(_) => (),
             None => {
@@ -829,6 +941,9 @@ impl<'invocation, 'bs> Runtime for InvocationCtx<'invocation, 'bs> {
                 "Calling send is not allowed during side-effect lock".to_string(),
             ));
         }
+        if !value.is_zero() {
+            self.check_not_read_only("send with value")?;
+        }
 
         let new_actor_msg = InternalMessage { from: self.to(), to: *to, value, method, params };
         let mut new_ctx = InvocationCtx {
@@ -839,6 +954,7 @@ impl<'invocation, 'bs> Runtime for InvocationCtx<'invocation, 'bs> {
             caller_validated: false,
             policy: self.policy,
             subinvocations: RefCell::new(vec![]),
+            read_only: self.read_only,
         };
         let res = new_ctx.invoke();
 
@@ -869,6 +985,7 @@ impl<'invocation, 'bs> Runtime for InvocationCtx<'invocation, 'bs> {
     }
 
     fn create<C: Cbor>(&mut self, obj: &C) -> Result<(), ActorError> {
+        self.check_not_read_only("create")?;
         let maybe_act = self.v.get_actor(self.to());
         match maybe_act {
             None => Err(ActorError::unchecked(
@@ -899,6 +1016,7 @@ impl<'invocation, 'bs> Runtime for InvocationCtx<'invocation, 'bs> {
         C: Cbor,
         F: FnOnce(&mut C, &mut Self) -> Result<RT, ActorError>,
     {
+        self.check_not_read_only("transaction")?;
         let mut st = self.state::<C>().unwrap();
         self.allow_side_effects = false;
         let result = f(&mut st, self);
@@ -919,8 +1037,24 @@ impl<'invocation, 'bs> Runtime for InvocationCtx<'invocation, 'bs> {
         Ok(Address::new_actor(&b))
     }
 
-    fn delete_actor(&mut self, _beneficiary: &Address) -> Result<(), ActorError> {
-        panic!("TODO implement me")
+    fn delete_actor(&mut self, beneficiary: &Address) -> Result<(), ActorError> {
+        self.check_not_read_only("delete_actor")?;
+        let receiver = self.to();
+        let beneficiary_id = match self.v.normalize_address(beneficiary) {
+            Some(id) if self.v.get_actor(id).is_some() => id,
+            _ => {
+                return Err(actor_error!(not_found; "beneficiary {} not found", beneficiary));
+            }
+        };
+        if beneficiary_id == receiver {
+            return Err(actor_error!(forbidden; "benefactor cannot be beneficiary"));
+        }
+        let balance = self.v.get_actor(receiver).unwrap().balance;
+        let mut beneficiary_actor = self.v.get_actor(beneficiary_id).unwrap();
+        beneficiary_actor.balance += balance;
+        self.v.set_actor(beneficiary_id, beneficiary_actor);
+        self.v.delete_actor(receiver);
+        Ok(())
     }
 
     fn resolve_builtin_actor_type(&self, code_id: &Cid) -> Option<Type> {
@@ -940,6 +1074,10 @@ impl<'invocation, 'bs> Runtime for InvocationCtx<'invocation, 'bs> {
     fn base_fee(&self) -> TokenAmount {
         TokenAmount::zero()
     }
+
+    fn read_only(&self) -> bool {
+        self.read_only
+    }
 }
 
 impl Primitives for VM<'_> {
@@ -970,6 +1108,10 @@ impl Primitives for VM<'_> {
             .unwrap()
     }
 
+    fn hash(&self, hasher: SupportedHashes, data: &[u8]) -> Vec<u8> {
+        fil_actors_runtime::test_utils::hash(hasher, data)
+    }
+
     fn compute_unsealed_sector_cid(
         &self,
         _proof_type: RegisteredSealProof,
@@ -993,6 +1135,10 @@ impl Primitives for InvocationCtx<'_, '_> {
         self.v.hash_blake2b(data)
     }
 
+    fn hash(&self, hasher: SupportedHashes, data: &[u8]) -> Vec<u8> {
+        self.v.hash(hasher, data)
+    }
+
     fn compute_unsealed_sector_cid(
         &self,
         proof_type: RegisteredSealProof,
@@ -1171,7 +1317,7 @@ impl ExpectInvocation {
         assert_eq!(
             self.method, invoc.msg.method,
             "{} unexpected method: expected: {}, was: {} \n{}",
-            id, self.method, invoc.msg.from, extra_msg
+            id, self.method, invoc.msg.method, extra_msg
         );
     }
 }
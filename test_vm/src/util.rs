@@ -254,8 +254,8 @@ pub fn precommit_sectors_v2(
     let expiration = match exp {
         None => {
             v.get_epoch()
-                + Policy::default().min_sector_expiration
-                + max_prove_commit_duration(&Policy::default(), seal_proof).unwrap()
+                + v.policy().min_sector_expiration
+                + max_prove_commit_duration(v.policy(), seal_proof).unwrap()
         }
         Some(e) => e,
     };
@@ -570,12 +570,8 @@ pub fn advance_by_deadline_to_epoch_while_proving(
             return v.with_epoch(e);
         }
         submit_windowed_post(&v, worker, maddr, dline_info, p_idx, None);
-        v = advance_by_deadline_to_index(
-            v,
-            maddr,
-            d + 1 % &Policy::default().wpost_period_deadlines,
-        )
-        .0
+        let next_deadline = (d + 1) % v.policy().wpost_period_deadlines;
+        v = advance_by_deadline_to_index(v, maddr, next_deadline).0
     }
 }
 
@@ -607,30 +603,79 @@ where
     }
 }
 
+/// Advances the VM to epoch `e`, running cron at every epoch on the way but not at `e` itself.
+/// Cron drives market deal settlement and miner deadline processing, so deals, vesting and
+/// fault penalties progress as they would on a live chain.
+pub fn advance_to_epoch_with_cron(mut v: VM, e: ChainEpoch) -> VM {
+    let mut epoch = v.get_epoch();
+    while epoch < e {
+        cron_tick(&v);
+        epoch += 1;
+        v = v.with_epoch(epoch);
+    }
+    v
+}
+
+/// Advances the VM to epoch `e` one deadline at a time, submitting a window PoSt for every
+/// partition of `maddr` with live sectors that has not yet been proven in each deadline it
+/// passes through. If `e` falls within a deadline, that deadline is left for the caller to prove.
+pub fn advance_by_deadline_to_epoch_while_proving_all(
+    mut v: VM,
+    maddr: Address,
+    worker: Address,
+    e: ChainEpoch,
+) -> VM {
+    loop {
+        let dline_info = miner_dline_info(&v, maddr);
+        if dline_info.close > e {
+            return advance_to_epoch_with_cron(v, e);
+        }
+        if dline_info.is_open() {
+            for p_idx in unproven_partitions(&v, maddr, dline_info.index) {
+                submit_windowed_post(&v, worker, maddr, dline_info, p_idx, None);
+            }
+        }
+        v = v.with_epoch(dline_info.last());
+        cron_tick(&v);
+        let next = v.get_epoch() + 1;
+        v = v.with_epoch(next);
+    }
+}
+
+fn unproven_partitions(v: &VM, m: Address, d_idx: u64) -> Vec<u64> {
+    let deadline = deadline_state(v, m, d_idx);
+    let mut partitions = vec![];
+    deadline
+        .for_each(v.store, |p_idx, partition| {
+            if !partition.live_sectors().is_empty() && !deadline.partitions_posted.get(p_idx) {
+                partitions.push(p_idx);
+            }
+            Ok(())
+        })
+        .unwrap();
+    partitions
+}
+
 pub fn miner_dline_info(v: &VM, m: Address) -> DeadlineInfo {
     let st = v.get_state::<MinerState>(m).unwrap();
-    new_deadline_info_from_offset_and_epoch(
-        &Policy::default(),
-        st.proving_period_start,
-        v.get_epoch(),
-    )
+    new_deadline_info_from_offset_and_epoch(v.policy(), st.proving_period_start, v.get_epoch())
 }
 
 pub fn sector_deadline(v: &VM, m: Address, s: SectorNumber) -> (u64, u64) {
     let st = v.get_state::<MinerState>(m).unwrap();
-    st.find_sector(&Policy::default(), v.store, s).unwrap()
+    st.find_sector(v.policy(), v.store, s).unwrap()
 }
 
 pub fn check_sector_active(v: &VM, m: Address, s: SectorNumber) -> bool {
     let (d_idx, p_idx) = sector_deadline(v, m, s);
     let st = v.get_state::<MinerState>(m).unwrap();
-    st.check_sector_active(&Policy::default(), v.store, d_idx, p_idx, s, true).unwrap()
+    st.check_sector_active(v.policy(), v.store, d_idx, p_idx, s, true).unwrap()
 }
 
 pub fn check_sector_faulty(v: &VM, m: Address, d_idx: u64, p_idx: u64, s: SectorNumber) -> bool {
     let st = v.get_state::<MinerState>(m).unwrap();
     let deadlines = st.load_deadlines(v.store).unwrap();
-    let deadline = deadlines.load_deadline(&Policy::default(), v.store, d_idx).unwrap();
+    let deadline = deadlines.load_deadline(v.policy(), v.store, d_idx).unwrap();
     let partition = deadline.load_partition(v.store, p_idx).unwrap();
     partition.faults.get(s)
 }
@@ -638,7 +683,7 @@ pub fn check_sector_faulty(v: &VM, m: Address, d_idx: u64, p_idx: u64, s: Sector
 pub fn deadline_state(v: &VM, m: Address, d_idx: u64) -> Deadline {
     let st = v.get_state::<MinerState>(m).unwrap();
     let deadlines = st.load_deadlines(v.store).unwrap();
-    deadlines.load_deadline(&Policy::default(), v.store, d_idx).unwrap()
+    deadlines.load_deadline(v.policy(), v.store, d_idx).unwrap()
 }
 
 pub fn sector_info(v: &VM, m: Address, s: SectorNumber) -> SectorOnChainInfo {
@@ -1203,8 +1248,8 @@ pub fn make_bitfield(bits: &[u64]) -> BitField {
     BitField::try_from_bits(bits.iter().copied()).unwrap()
 }
 
-pub fn bf_all(bf: BitField) -> Vec<u64> {
-    bf.bounded_iter(Policy::default().addressed_sectors_max).unwrap().collect()
+pub fn bf_all(v: &VM, bf: BitField) -> Vec<u64> {
+    bf.bounded_iter(v.policy().addressed_sectors_max).unwrap().collect()
 }
 
 pub mod invariant_failure_patterns {
@@ -0,0 +1,413 @@
+use std::collections::BTreeMap;
+
+use cid::Cid;
+use fil_actor_account::types::AuthenticateMessageParams;
+use fil_actor_cron::{Entry, State as CronState};
+use fil_actor_datacap::MintParams;
+use fil_actor_init::ExecReturn;
+use fil_actor_market::{
+    ClientDealProposal, DealProposal, DealState, Label, PublishStorageDealsParams,
+    State as MarketState, WithdrawBalanceParams as MarketWithdrawBalanceParams,
+};
+use fil_actor_miner::{
+    BeneficiaryTerm, ChangeWorkerAddressParams, CompactCommD, GetDeadlinePartitionCountParams,
+    MinerInfo, PendingBeneficiaryChange, PreCommitSectorBatchParams2, SectorOnChainInfo,
+    SectorPreCommitInfo, State as MinerState, WithdrawBalanceParams as MinerWithdrawBalanceParams,
+    WorkerKeyChange,
+};
+use fil_actor_multisig::{ProposeParams, TxnID, TxnIDParams};
+use fil_actor_paych::ConstructorParams as PaychConstructorParams;
+use fil_actor_power::{
+    CreateMinerParams, CreateMinerReturn, State as PowerState, UpdateClaimedPowerParams,
+};
+use fil_actor_reward::{AwardBlockRewardParams, State as RewardState};
+use fil_actor_verifreg::{Allocation, Claim, State as VerifregState, VerifierParams};
+use fil_actors_runtime::test_utils::make_builtin;
+use fil_actors_runtime::DealWeight;
+use fvm_ipld_bitfield::BitField;
+use fvm_ipld_encoding::{to_vec, BytesDe, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::BigInt;
+use fvm_shared::crypto::signature::Signature;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::piece::PaddedPieceSize;
+use fvm_shared::sector::{
+    RegisteredPoStProof, RegisteredSealProof, SectorSize, Spacetime, StoragePower,
+};
+use fvm_shared::smooth::FilterEstimate;
+use serde::Serialize;
+
+const FIXTURES: &str = include_str!("fixtures/cbor_golden.txt");
+
+fn load_fixtures() -> BTreeMap<&'static str, &'static str> {
+    FIXTURES
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split_once(' ').expect("fixture line must be `<name> <hex>`"))
+        .collect()
+}
+
+/// Checks the encoding of a value against its golden fixture, removing the fixture once checked.
+fn check<T: Serialize>(fixtures: &mut BTreeMap<&str, &str>, name: &str, value: T) {
+    let expected = fixtures.remove(name).unwrap_or_else(|| panic!("no fixture for {}", name));
+    let actual = hex::encode(to_vec(&value).unwrap());
+    assert_eq!(expected, actual, "encoding of {} changed", name);
+}
+
+fn id(id: u64) -> Address {
+    Address::new_id(id)
+}
+
+fn cid(name: &[u8]) -> Cid {
+    make_builtin(name)
+}
+
+fn atto(amount: u64) -> TokenAmount {
+    TokenAmount::from_atto(amount)
+}
+
+fn zero_estimate() -> FilterEstimate {
+    FilterEstimate::new(BigInt::from(0), BigInt::from(0))
+}
+
+fn deal_proposal() -> DealProposal {
+    DealProposal {
+        piece_cid: cid(b"piece"),
+        piece_size: PaddedPieceSize(2048),
+        verified_deal: true,
+        client: id(101),
+        provider: id(102),
+        label: Label::String("label".to_owned()),
+        start_epoch: 100,
+        end_epoch: 200,
+        storage_price_per_epoch: atto(10),
+        provider_collateral: atto(20),
+        client_collateral: atto(0),
+    }
+}
+
+fn client_deal_proposal() -> ClientDealProposal {
+    ClientDealProposal {
+        proposal: deal_proposal(),
+        client_signature: Signature::new_bls(vec![1, 2, 3]),
+    }
+}
+
+fn sector_pre_commit_info(unsealed_cid: Option<Cid>) -> SectorPreCommitInfo {
+    SectorPreCommitInfo {
+        seal_proof: RegisteredSealProof::StackedDRG32GiBV1P1,
+        sector_number: 7,
+        sealed_cid: cid(b"sealed"),
+        seal_rand_epoch: 90,
+        deal_ids: vec![1],
+        expiration: 600,
+        unsealed_cid: CompactCommD::new(unsealed_cid),
+    }
+}
+
+#[test]
+fn encodings_match_golden_fixtures() {
+    let mut fixtures = load_fixtures();
+    let f = &mut fixtures;
+
+    check(f, "account/State", fil_actor_account::State { address: id(110) });
+    check(
+        f,
+        "account/AuthenticateMessageParams",
+        AuthenticateMessageParams { signature: vec![1, 2, 3], message: b"msg".to_vec() },
+    );
+    check(
+        f,
+        "cron/State",
+        CronState {
+            entries: vec![
+                Entry { receiver: id(100), method_num: 2 },
+                Entry { receiver: id(101), method_num: 3 },
+            ],
+        },
+    );
+    check(
+        f,
+        "datacap/MintParams",
+        MintParams { to: id(108), amount: TokenAmount::from_whole(2), operators: vec![id(109)] },
+    );
+    check(
+        f,
+        "init/ExecReturn",
+        ExecReturn { id_address: id(1000), robust_address: Address::new_actor(b"robust") },
+    );
+    check(
+        f,
+        "market/WithdrawBalanceParams",
+        MarketWithdrawBalanceParams {
+            provider_or_client: id(102),
+            amount: TokenAmount::from_whole(1),
+        },
+    );
+    check(
+        f,
+        "miner/ChangeWorkerAddressParams",
+        ChangeWorkerAddressParams {
+            new_worker: id(103),
+            new_control_addresses: vec![id(104), id(105)],
+        },
+    );
+    check(
+        f,
+        "miner/GetDeadlinePartitionCountParams",
+        GetDeadlinePartitionCountParams { deadline: 47 },
+    );
+    check(
+        f,
+        "miner/WithdrawBalanceParams",
+        MinerWithdrawBalanceParams { amount_requested: TokenAmount::from_atto(0) },
+    );
+    check(
+        f,
+        "multisig/ProposeParams",
+        ProposeParams {
+            to: id(101),
+            value: TokenAmount::from_atto(500),
+            method: 2,
+            params: RawBytes::new(vec![0xde, 0xad]),
+        },
+    );
+    check(f, "multisig/TxnIDParams", TxnIDParams { id: TxnID(7), proposal_hash: vec![0xab; 32] });
+    check(f, "paych/ConstructorParams", PaychConstructorParams { from: id(100), to: id(101) });
+    check(
+        f,
+        "power/CreateMinerParams",
+        CreateMinerParams {
+            owner: id(100),
+            worker: id(101),
+            window_post_proof_type: RegisteredPoStProof::StackedDRGWindow32GiBV1,
+            peer: b"peer".to_vec(),
+            multiaddrs: vec![BytesDe(b"addr".to_vec())],
+        },
+    );
+    check(
+        f,
+        "power/CreateMinerReturn",
+        CreateMinerReturn { id_address: id(1001), robust_address: Address::new_actor(b"miner") },
+    );
+    check(
+        f,
+        "power/UpdateClaimedPowerParams",
+        UpdateClaimedPowerParams {
+            raw_byte_delta: StoragePower::from(-1024),
+            quality_adjusted_delta: StoragePower::from(2048),
+        },
+    );
+    check(
+        f,
+        "reward/AwardBlockRewardParams",
+        AwardBlockRewardParams {
+            miner: id(107),
+            penalty: TokenAmount::from_atto(0),
+            gas_reward: TokenAmount::from_atto(1000),
+            win_count: 1,
+        },
+    );
+    check(
+        f,
+        "verifreg/VerifierParams",
+        VerifierParams { address: id(106), allowance: StoragePower::from(1u64 << 40) },
+    );
+
+    check_market_encodings(f);
+    check_miner_encodings(f);
+    check_power_reward_and_verifreg_encodings(f);
+
+    assert!(fixtures.is_empty(), "fixtures not checked: {:?}", fixtures.keys());
+}
+
+fn check_market_encodings(f: &mut BTreeMap<&str, &str>) {
+    check(f, "market/DealProposal", deal_proposal());
+    check(f, "market/ClientDealProposal", client_deal_proposal());
+    check(
+        f,
+        "market/PublishStorageDealsParams",
+        PublishStorageDealsParams { deals: vec![client_deal_proposal()] },
+    );
+    check(
+        f,
+        "market/DealState",
+        DealState {
+            sector_start_epoch: 10,
+            last_updated_epoch: -1,
+            slash_epoch: -1,
+            verified_claim: 5,
+        },
+    );
+    check(
+        f,
+        "market/State",
+        MarketState {
+            proposals: cid(b"proposals"),
+            states: cid(b"states"),
+            pending_proposals: cid(b"pending"),
+            escrow_table: cid(b"escrow"),
+            locked_table: cid(b"locked"),
+            next_id: 3,
+            deal_ops_by_epoch: cid(b"ops"),
+            last_cron: -1,
+            total_client_locked_collateral: atto(1),
+            total_provider_locked_collateral: atto(2),
+            total_client_storage_fee: atto(3),
+            pending_deal_allocation_ids: cid(b"allocs"),
+        },
+    );
+}
+
+fn check_miner_encodings(f: &mut BTreeMap<&str, &str>) {
+    check(
+        f,
+        "miner/State",
+        MinerState {
+            info: cid(b"info"),
+            pre_commit_deposits: atto(1),
+            locked_funds: atto(2),
+            vesting_funds: cid(b"vesting"),
+            fee_debt: atto(0),
+            initial_pledge: atto(3),
+            pre_committed_sectors: cid(b"precommits"),
+            pre_committed_sectors_cleanup: cid(b"cleanup"),
+            allocated_sectors: cid(b"allocated"),
+            sectors: cid(b"sectors"),
+            proving_period_start: 100,
+            current_deadline: 2,
+            deadlines: cid(b"deadlines"),
+            early_terminations: BitField::new(),
+            deadline_cron_active: true,
+        },
+    );
+    check(
+        f,
+        "miner/MinerInfo",
+        MinerInfo {
+            owner: id(100),
+            worker: id(101),
+            control_addresses: vec![id(102)],
+            pending_worker_key: Some(WorkerKeyChange { new_worker: id(103), effective_at: 500 }),
+            peer_id: b"peer".to_vec(),
+            multi_address: vec![BytesDe(b"addr".to_vec())],
+            window_post_proof_type: RegisteredPoStProof::StackedDRGWindow32GiBV1,
+            sector_size: SectorSize::_32GiB,
+            window_post_partition_sectors: 2349,
+            consensus_fault_elapsed: -1,
+            pending_owner_address: None,
+            beneficiary: id(100),
+            beneficiary_term: BeneficiaryTerm::new(atto(1000), atto(10), 900),
+            pending_beneficiary_term: Some(PendingBeneficiaryChange::new(id(104), atto(500), 1000)),
+        },
+    );
+    check(
+        f,
+        "miner/SectorOnChainInfo",
+        SectorOnChainInfo {
+            sector_number: 7,
+            seal_proof: RegisteredSealProof::StackedDRG32GiBV1P1,
+            sealed_cid: cid(b"sealed"),
+            deal_ids: vec![1, 2],
+            activation: 100,
+            expiration: 600,
+            deal_weight: DealWeight::from(1000),
+            verified_deal_weight: DealWeight::from(0),
+            initial_pledge: atto(5),
+            expected_day_reward: atto(6),
+            expected_storage_pledge: atto(7),
+            replaced_sector_age: 0,
+            replaced_day_reward: atto(0),
+            sector_key_cid: None,
+            simple_qa_power: true,
+        },
+    );
+    check(f, "miner/SectorPreCommitInfo", sector_pre_commit_info(None));
+    check(
+        f,
+        "miner/PreCommitSectorBatchParams2",
+        PreCommitSectorBatchParams2 {
+            sectors: vec![sector_pre_commit_info(Some(cid(b"unsealed")))],
+        },
+    );
+}
+
+fn check_power_reward_and_verifreg_encodings(f: &mut BTreeMap<&str, &str>) {
+    check(
+        f,
+        "power/State",
+        PowerState {
+            total_raw_byte_power: StoragePower::from(1024),
+            total_bytes_committed: StoragePower::from(2048),
+            total_quality_adj_power: StoragePower::from(10240),
+            total_qa_bytes_committed: StoragePower::from(20480),
+            total_pledge_collateral: atto(100),
+            this_epoch_raw_byte_power: StoragePower::from(1024),
+            this_epoch_quality_adj_power: StoragePower::from(10240),
+            this_epoch_pledge_collateral: atto(50),
+            this_epoch_qa_power_smoothed: zero_estimate(),
+            miner_count: 3,
+            miner_above_min_power_count: 1,
+            cron_event_queue: cid(b"cron"),
+            first_cron_epoch: 10,
+            claims: cid(b"claims"),
+            proof_validation_batch: None,
+        },
+    );
+    check(
+        f,
+        "reward/State",
+        RewardState {
+            cumsum_baseline: Spacetime::from(100),
+            cumsum_realized: Spacetime::from(50),
+            effective_network_time: 10,
+            effective_baseline_power: StoragePower::from(1024),
+            this_epoch_reward: atto(1000),
+            this_epoch_reward_smoothed: zero_estimate(),
+            this_epoch_baseline_power: StoragePower::from(2048),
+            epoch: 11,
+            total_storage_power_reward: atto(5000),
+            simple_total: atto(1_000_000),
+            baseline_total: atto(2_000_000),
+        },
+    );
+    check(
+        f,
+        "verifreg/State",
+        VerifregState {
+            root_key: id(80),
+            verifiers: cid(b"verifiers"),
+            remove_data_cap_proposal_ids: cid(b"removals"),
+            allocations: cid(b"allocations"),
+            next_allocation_id: 4,
+            claims: cid(b"claims"),
+        },
+    );
+    check(
+        f,
+        "verifreg/Allocation",
+        Allocation {
+            client: 101,
+            provider: 102,
+            data: cid(b"data"),
+            size: PaddedPieceSize(2048),
+            term_min: 518_400,
+            term_max: 5_256_000,
+            expiration: 1000,
+        },
+    );
+    check(
+        f,
+        "verifreg/Claim",
+        Claim {
+            provider: 102,
+            client: 101,
+            data: cid(b"data"),
+            size: PaddedPieceSize(2048),
+            term_min: 518_400,
+            term_max: 5_256_000,
+            term_start: 200,
+            sector: 7,
+        },
+    );
+}
@@ -24,9 +24,9 @@ use fvm_shared::randomness::Randomness;
 use fvm_shared::sector::{PoStProof, RegisteredSealProof, SectorNumber, MAX_SECTOR_NUMBER};
 use fvm_shared::METHOD_SEND;
 use test_vm::util::{
-    advance_by_deadline_to_epoch, advance_to_proving_deadline, apply_code, apply_ok,
-    create_accounts, create_miner, invariant_failure_patterns, precommit_sectors,
-    submit_windowed_post,
+    advance_by_deadline_to_epoch, advance_by_deadline_to_epoch_while_proving_all,
+    advance_to_proving_deadline, apply_code, apply_ok, check_sector_faulty, create_accounts,
+    create_miner, invariant_failure_patterns, miner_power, precommit_sectors, submit_windowed_post,
 };
 use test_vm::{ExpectInvocation, TEST_VM_RAND_ARRAY, VM};
 
@@ -191,6 +191,36 @@ fn submit_post_succeeds() {
     v.assert_state_invariants();
 }
 
+#[test]
+fn proving_over_many_proving_periods() {
+    let store = MemoryBlockstore::new();
+    let (v, miner_info, sector_info) = setup(&store);
+    let st = v.get_state::<MinerState>(miner_info.miner_id).unwrap();
+    let sector = st.get_sector(v.store, sector_info.number).unwrap().unwrap();
+    let sector_power = power_for_sector(miner_info.seal_proof.sector_size().unwrap(), &sector);
+
+    // prove every deadline for three full proving periods, with cron running every epoch
+    let end = v.get_epoch() + 3 * Policy::default().wpost_proving_period;
+    let v = advance_by_deadline_to_epoch_while_proving_all(
+        v,
+        miner_info.miner_id,
+        miner_info.worker,
+        end,
+    );
+    assert_eq!(end, v.get_epoch());
+
+    assert!(!check_sector_faulty(
+        &v,
+        miner_info.miner_id,
+        sector_info.deadline_info.index,
+        sector_info.partition_index,
+        sector_info.number
+    ));
+    assert_eq!(sector_power, miner_power(&v, miner_info.miner_id));
+
+    v.assert_state_invariants();
+}
+
 #[test]
 fn skip_sector() {
     let store = MemoryBlockstore::new();
@@ -0,0 +1,117 @@
+use cid::multihash::{Code, MultihashDigest};
+use cid::Cid;
+use fil_actors_runtime::runtime::builtins::Type;
+use fil_actors_runtime::test_utils::{ACCOUNT_ACTOR_CODE_ID, ACTOR_TYPES};
+use fil_builtin_actors_state::manifest::Manifest;
+use futures::executor::block_on;
+use fvm_ipld_blockstore::{Blockstore, MemoryBlockstore};
+use fvm_ipld_car::CarHeader;
+use fvm_ipld_encoding::{to_vec, CborStore, DAG_CBOR};
+
+const IPLD_RAW: u64 = 0x55;
+
+fn put_manifest(store: &MemoryBlockstore, version: u32, entries: &[(String, Cid)]) -> Cid {
+    let data = store.put_cbor(&entries, Code::Blake2b256).unwrap();
+    store.put_cbor(&(version, data), Code::Blake2b256).unwrap()
+}
+
+fn test_entries() -> Vec<(String, Cid)> {
+    ACTOR_TYPES.iter().map(|(code, typ)| (typ.name().to_owned(), *code)).collect()
+}
+
+fn cbor_block<T: serde::Serialize>(obj: &T) -> (Cid, Vec<u8>) {
+    let data = to_vec(obj).unwrap();
+    (Cid::new_v1(DAG_CBOR, Code::Blake2b256.digest(&data)), data)
+}
+
+fn write_car(roots: Vec<Cid>, blocks: Vec<(Cid, Vec<u8>)>) -> Vec<u8> {
+    let mut car = Vec::new();
+    let mut stream = futures::stream::iter(blocks);
+    block_on(CarHeader::new(roots, 1).write_stream_async(&mut car, &mut stream)).unwrap();
+    car
+}
+
+#[test]
+fn load_maps_names_and_types_to_code() {
+    let store = MemoryBlockstore::new();
+    let root = put_manifest(&store, Manifest::VERSION, &test_entries());
+
+    let manifest = Manifest::load(&store, &root).unwrap();
+    assert_eq!(Some(&*ACCOUNT_ACTOR_CODE_ID), manifest.code_by_name("account"));
+    assert_eq!(Some(&*ACCOUNT_ACTOR_CODE_ID), manifest.code_by_type(Type::Account));
+    assert_eq!(Some(Type::Account), manifest.type_by_code(&ACCOUNT_ACTOR_CODE_ID));
+    assert_eq!(ACTOR_TYPES.len(), manifest.builtin_actors().len());
+    manifest.validate(&store).unwrap();
+}
+
+#[test]
+fn load_car_imports_bundle_and_loads_root() {
+    let (data_cid, data) = cbor_block(&test_entries());
+    let (root_cid, root) = cbor_block(&(Manifest::VERSION, data_cid));
+    let blocks = vec![(root_cid, root), (data_cid, data)];
+
+    let store = MemoryBlockstore::new();
+    let car = write_car(vec![root_cid], blocks.clone());
+    let manifest = Manifest::load_car(&store, car.as_slice()).unwrap();
+    assert_eq!(Some(&*ACCOUNT_ACTOR_CODE_ID), manifest.code_by_type(Type::Account));
+    assert_eq!(ACTOR_TYPES.len(), manifest.builtin_actors().len());
+    assert!(store.has(&root_cid).unwrap());
+    assert!(store.has(&data_cid).unwrap());
+
+    // A bundle has exactly one root, the manifest.
+    let car = write_car(vec![root_cid, data_cid], blocks);
+    let err = Manifest::load_car(&MemoryBlockstore::new(), car.as_slice()).unwrap_err();
+    assert!(err.to_string().contains("exactly one root"));
+}
+
+#[test]
+fn load_rejects_unknown_version() {
+    let store = MemoryBlockstore::new();
+    let root = put_manifest(&store, Manifest::VERSION + 1, &test_entries());
+
+    let err = Manifest::load(&store, &root).unwrap_err();
+    assert!(err.to_string().contains("unsupported manifest version"));
+}
+
+#[test]
+fn load_rejects_duplicate_names() {
+    let store = MemoryBlockstore::new();
+    let mut entries = test_entries();
+    entries.push(("account".to_owned(), Cid::new_v1(IPLD_RAW, Code::Sha2_256.digest(b"dup"))));
+    let root = put_manifest(&store, Manifest::VERSION, &entries);
+
+    let err = Manifest::load(&store, &root).unwrap_err();
+    assert!(err.to_string().contains("duplicate actor name"));
+}
+
+#[test]
+fn validate_requires_all_actors_and_code() {
+    let store = MemoryBlockstore::new();
+    let mut entries = test_entries();
+    entries.retain(|(name, _)| name != "datacap");
+    let manifest = Manifest::from_entries(entries.clone()).unwrap();
+    let err = manifest.validate(&store).unwrap_err();
+    assert!(err.to_string().contains("missing actor datacap"));
+
+    // Code which isn't inlined in its CID must be present in the store.
+    let wasm = b"datacap wasm".to_vec();
+    let code = Cid::new_v1(IPLD_RAW, Code::Sha2_256.digest(&wasm));
+    entries.push(("datacap".to_owned(), code));
+    let manifest = Manifest::from_entries(entries).unwrap();
+    let err = manifest.validate(&store).unwrap_err();
+    assert!(err.to_string().contains("not found in store"));
+
+    store.put_keyed(&code, &wasm).unwrap();
+    manifest.validate(&store).unwrap();
+}
+
+#[test]
+fn unknown_names_are_kept_untyped() {
+    let mut entries = test_entries();
+    let code = Cid::new_v1(IPLD_RAW, Code::Sha2_256.digest(b"other"));
+    entries.push(("other".to_owned(), code));
+    let manifest = Manifest::from_entries(entries).unwrap();
+    assert_eq!(Some(&code), manifest.code_by_name("other"));
+    assert_eq!(None, manifest.type_by_code(&code));
+    assert_eq!(ACTOR_TYPES.len() + 1, manifest.actors().count());
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use cid::multihash::Code;
+use cid::Cid;
+use fil_actors_runtime::test_utils::{
+    make_builtin, ACCOUNT_ACTOR_CODE_ID, ACTOR_CODES, MULTISIG_ACTOR_CODE_ID,
+};
+use fil_actors_runtime::Map;
+use fil_builtin_actors_state::check::Actor;
+use fil_builtin_actors_state::migration::{
+    ActorMigration, ActorMigrationInput, ActorMigrationOutput, MigrationCache, NilMigrator,
+    StateMigration,
+};
+use fvm_ipld_blockstore::{Blockstore, MemoryBlockstore};
+use fvm_ipld_encoding::CborStore;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use test_vm::{TEST_FAUCET_ADDR, VM};
+
+#[test]
+fn nil_migration_preserves_state_tree() {
+    let store = MemoryBlockstore::new();
+    let v = VM::new_with_singletons(&store);
+    let prior_root = v.checkpoint();
+
+    let mut migration = StateMigration::new();
+    for code in ACTOR_CODES.values() {
+        migration.add_migrator(*code, Arc::new(NilMigrator::new(*code)));
+    }
+    let cache = MigrationCache::new();
+    let new_root = migration.migrate_state_tree(&store, &prior_root, 0, &cache).unwrap();
+    assert_eq!(prior_root, new_root);
+}
+
+#[test]
+fn migration_updates_code_cids() {
+    let store = MemoryBlockstore::new();
+    let v = VM::new_with_singletons(&store);
+    let prior_root = v.checkpoint();
+
+    let new_account_code = make_builtin(b"fil/test/account-v2");
+    let mut migration = StateMigration::new();
+    for code in ACTOR_CODES.values() {
+        let new_code = if code == &*ACCOUNT_ACTOR_CODE_ID { new_account_code } else { *code };
+        migration.add_migrator(*code, Arc::new(NilMigrator::new(new_code)));
+    }
+    let cache = MigrationCache::new();
+    let new_root = migration.migrate_state_tree(&store, &prior_root, 0, &cache).unwrap();
+    assert_ne!(prior_root, new_root);
+
+    v.rollback(new_root);
+    let faucet = v.get_actor(TEST_FAUCET_ADDR).unwrap();
+    assert_eq!(new_account_code, faucet.code);
+}
+
+#[test]
+fn migration_fails_for_unknown_code() {
+    let store = MemoryBlockstore::new();
+    let v = VM::new_with_singletons(&store);
+    let prior_root = v.checkpoint();
+
+    let migration = StateMigration::new();
+    let cache = MigrationCache::new();
+    let err = migration.migrate_state_tree(&store, &prior_root, 0, &cache).unwrap_err();
+    assert!(err.to_string().contains("no migration registered"));
+}
+
+/// A blockstore which can be shared between migration workers.
+#[derive(Default)]
+struct SyncBlockstore(Mutex<HashMap<Cid, Vec<u8>>>);
+
+impl Blockstore for SyncBlockstore {
+    fn get(&self, k: &Cid) -> anyhow::Result<Option<Vec<u8>>> {
+        Ok(self.0.lock().unwrap().get(k).cloned())
+    }
+
+    fn put_keyed(&self, k: &Cid, block: &[u8]) -> anyhow::Result<()> {
+        self.0.lock().unwrap().insert(*k, block.to_vec());
+        Ok(())
+    }
+}
+
+/// Writes a state tree of alternating accounts and multisigs with distinct heads and balances.
+fn make_tree(store: &SyncBlockstore, count: u64) -> Cid {
+    let mut map: Map<_, Actor> = Map::new(store);
+    for id in 100..100 + count {
+        let code = if id % 2 == 0 { *ACCOUNT_ACTOR_CODE_ID } else { *MULTISIG_ACTOR_CODE_ID };
+        let head = store.put_cbor(&id, Code::Blake2b256).unwrap();
+        let actor = Actor { code, head, call_seq_num: id, balance: TokenAmount::from_atto(id) };
+        map.set(Address::new_id(id).to_bytes().into(), actor).unwrap();
+    }
+    map.flush().unwrap()
+}
+
+/// Rewrites each actor's head to a new block depending on all of its inputs and on a
+/// structure shared through the cache, failing for one chosen actor.
+struct RewriteHeadMigrator {
+    new_code_cid: Cid,
+    fail_for: Option<Address>,
+}
+
+impl<BS: Blockstore> ActorMigration<BS> for RewriteHeadMigrator {
+    fn migrate_state(
+        &self,
+        store: &BS,
+        input: ActorMigrationInput,
+        cache: &MigrationCache,
+    ) -> anyhow::Result<ActorMigrationOutput> {
+        if self.fail_for == Some(input.address) {
+            return Err(anyhow!("refusing to migrate"));
+        }
+        let shared = cache.get_or_insert_with("shared".to_owned(), || {
+            store.put_cbor(&"shared", Code::Blake2b256)
+        })?;
+        let new_head = store.put_cbor(
+            &(input.head, input.address, input.balance, input.prior_epoch, shared),
+            Code::Blake2b256,
+        )?;
+        Ok(ActorMigrationOutput { new_code_cid: self.new_code_cid, new_head })
+    }
+}
+
+fn rewrite_migration(fail_for: Option<Address>) -> StateMigration<SyncBlockstore> {
+    let mut migration = StateMigration::new();
+    let account_migrator =
+        RewriteHeadMigrator { new_code_cid: make_builtin(b"fil/test/account-v2"), fail_for };
+    migration.add_migrator(*ACCOUNT_ACTOR_CODE_ID, Arc::new(account_migrator));
+    let multisig_migrator = RewriteHeadMigrator { new_code_cid: *MULTISIG_ACTOR_CODE_ID, fail_for };
+    migration.add_migrator(*MULTISIG_ACTOR_CODE_ID, Arc::new(multisig_migrator));
+    migration
+}
+
+const WORKER_COUNTS: [usize; 7] = [0, 1, 2, 3, 7, 50, 64];
+
+#[test]
+fn parallel_migration_matches_sequential() {
+    let store = SyncBlockstore::default();
+    let migration = rewrite_migration(None);
+    for count in [0, 1, 50] {
+        let prior_root = make_tree(&store, count);
+        let expected =
+            migration.migrate_state_tree(&store, &prior_root, 10, &MigrationCache::new()).unwrap();
+        if count > 0 {
+            assert_ne!(prior_root, expected);
+        }
+        for workers in WORKER_COUNTS {
+            let cache = MigrationCache::new();
+            let root = migration
+                .migrate_state_tree_parallel(&store, &prior_root, 10, &cache, workers)
+                .unwrap();
+            assert_eq!(expected, root, "{} actors on {} workers", count, workers);
+        }
+    }
+}
+
+#[test]
+fn parallel_migration_reports_migrator_error() {
+    let store = SyncBlockstore::default();
+    let prior_root = make_tree(&store, 50);
+    let migration = rewrite_migration(Some(Address::new_id(136)));
+    let expected =
+        migration.migrate_state_tree(&store, &prior_root, 10, &MigrationCache::new()).unwrap_err();
+    assert!(expected.to_string().contains("refusing to migrate"));
+
+    for workers in WORKER_COUNTS {
+        let cache = MigrationCache::new();
+        let err = migration
+            .migrate_state_tree_parallel(&store, &prior_root, 10, &cache, workers)
+            .unwrap_err();
+        assert_eq!(expected.to_string(), err.to_string(), "{} workers", workers);
+    }
+}
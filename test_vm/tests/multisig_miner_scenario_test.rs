@@ -0,0 +1,315 @@
+use fil_actor_init::ExecReturn;
+use fil_actor_market::{
+    DealArray, DealMetaArray, Method as MarketMethod, State as MarketState,
+    WithdrawBalanceParams as MarketWithdrawBalanceParams,
+};
+use fil_actor_miner::{
+    max_prove_commit_duration, Method as MinerMethod, PowerPair, TerminateSectorsParams,
+    TerminationDeclaration,
+};
+use fil_actor_multisig::{Method as MsigMethod, ProposeParams, ProposeReturn};
+use fil_actor_power::{
+    CreateMinerParams, CreateMinerReturn, Method as PowerMethod, MinerConsensusEligibilityParams,
+    MinerConsensusEligibilityReturn,
+};
+use fil_actor_verifreg::{
+    Claim, Method as VerifregMethod, State as VerifregState, UpdateVerifiersParams, VerifierUpdate,
+};
+use fil_actors_runtime::cbor::serialize;
+use fil_actors_runtime::runtime::policy_constants::MARKET_DEFAULT_ALLOCATION_TERM_BUFFER;
+use fil_actors_runtime::test_utils::{make_piece_cid, MULTISIG_ACTOR_CODE_ID};
+use fil_actors_runtime::{
+    BatchReturn, EPOCHS_IN_DAY, INIT_ACTOR_ADDR, STORAGE_MARKET_ACTOR_ADDR,
+    STORAGE_POWER_ACTOR_ADDR, VERIFIED_REGISTRY_ACTOR_ADDR,
+};
+use fvm_ipld_blockstore::MemoryBlockstore;
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::piece::PaddedPieceSize;
+use fvm_shared::sector::{RegisteredSealProof, SectorNumber, StoragePower};
+use fvm_shared::METHOD_SEND;
+use num_traits::Zero;
+use test_vm::util::{
+    advance_by_deadline_to_epoch, advance_by_deadline_to_epoch_while_proving,
+    advance_to_proving_deadline, apply_ok, create_accounts, cron_tick, datacap_get_balance,
+    invariant_failure_patterns, make_bitfield, market_add_balance, market_publish_deal,
+    miner_power, miner_precommit_sector, miner_prove_sector, submit_windowed_post,
+    verifreg_add_client,
+};
+use test_vm::{TEST_VERIFREG_ROOT_ADDR, TEST_VERIFREG_ROOT_SIGNER_ADDR, VM};
+
+// A miner owned by a multisig stores a verified deal from a client whose verifier was added by
+// the registry root's batch update. The sector is proven and then terminated, and the market
+// settles the deal, exercising the init, multisig, power, miner, market, verifreg and datacap
+// actors together.
+#[test]
+fn multisig_owned_miner_verified_deal_lifecycle() {
+    let store = MemoryBlockstore::new();
+    let v = VM::new_with_singletons(&store);
+    let seal_proof = RegisteredSealProof::StackedDRG32GiBV1P1;
+    let sector_number: SectorNumber = 100;
+    let addrs = create_accounts(&v, 4, TokenAmount::from_whole(10_000));
+    let (signer, worker, verifier, client) = (addrs[0], addrs[1], addrs[2], addrs[3]);
+
+    // Create a 1-of-1 multisig and propose that it create a miner it owns.
+    let msig = create_msig(&v, vec![signer], 1);
+    apply_ok(&v, signer, msig, TokenAmount::from_whole(1_000), METHOD_SEND, RawBytes::default());
+    let create_miner_params = CreateMinerParams {
+        owner: msig,
+        worker,
+        window_post_proof_type: seal_proof.registered_window_post_proof().unwrap(),
+        peer: "miner".as_bytes().to_vec(),
+        multiaddrs: vec![BytesDe("multiaddr".as_bytes().to_vec())],
+    };
+    let proposal = ProposeParams {
+        to: STORAGE_POWER_ACTOR_ADDR,
+        value: TokenAmount::zero(),
+        method: PowerMethod::CreateMiner as u64,
+        params: serialize(&create_miner_params, "create miner params").unwrap(),
+    };
+    let ret: ProposeReturn =
+        apply_ok(&v, signer, msig, TokenAmount::zero(), MsigMethod::Propose as u64, proposal)
+            .deserialize()
+            .unwrap();
+    assert!(ret.applied);
+    assert_eq!(ExitCode::OK, ret.code);
+    let miner: CreateMinerReturn = ret.ret.deserialize().unwrap();
+
+    let info = v.get_miner_info(miner.id_address);
+    assert_eq!(msig, info.owner);
+    assert_eq!(v.normalize_address(&worker).unwrap(), info.worker);
+
+    // The new miner has a claim but no power, so isn't yet eligible for consensus.
+    let eligibility: MinerConsensusEligibilityReturn = apply_ok(
+        &v,
+        worker,
+        STORAGE_POWER_ACTOR_ADDR,
+        TokenAmount::zero(),
+        PowerMethod::MinerConsensusEligibility as u64,
+        MinerConsensusEligibilityParams { miner: miner.id_address.id().unwrap() },
+    )
+    .deserialize()
+    .unwrap();
+    assert_eq!(MinerConsensusEligibilityReturn { has_claim: true, eligible: false }, eligibility);
+
+    // The registry root adds a verifier in a batch, which then allocates datacap to a client.
+    let verifier_allowance = StoragePower::from(1u64 << 40);
+    let update_params = UpdateVerifiersParams {
+        updates: vec![VerifierUpdate { address: verifier, allowance: verifier_allowance.clone() }],
+        all_or_nothing: true,
+    };
+    let proposal = ProposeParams {
+        to: VERIFIED_REGISTRY_ACTOR_ADDR,
+        value: TokenAmount::zero(),
+        method: VerifregMethod::UpdateVerifiers as u64,
+        params: serialize(&update_params, "update verifiers params").unwrap(),
+    };
+    let ret: ProposeReturn = apply_ok(
+        &v,
+        TEST_VERIFREG_ROOT_SIGNER_ADDR,
+        TEST_VERIFREG_ROOT_ADDR,
+        TokenAmount::zero(),
+        MsigMethod::Propose as u64,
+        proposal,
+    )
+    .deserialize()
+    .unwrap();
+    assert_eq!(ExitCode::OK, ret.code);
+    let batch: BatchReturn = ret.ret.deserialize().unwrap();
+    assert!(batch.all_ok());
+
+    let deal_size = 32u64 << 30;
+    let client_allowance = StoragePower::from(deal_size);
+    verifreg_add_client(&v, verifier, client, client_allowance.clone());
+    assert_eq!(TokenAmount::from_whole(client_allowance.clone()), datacap_get_balance(&v, client));
+
+    let verifier_id = v.normalize_address(&verifier).unwrap();
+    let st = v.get_state::<VerifregState>(VERIFIED_REGISTRY_ACTOR_ADDR).unwrap();
+    assert_eq!(
+        Some(verifier_allowance - client_allowance),
+        st.get_verifier_cap(v.store, &verifier_id).unwrap()
+    );
+
+    // The worker funds the miner, and both parties add market collateral.
+    let miner_id = miner.id_address;
+    apply_ok(
+        &v,
+        worker,
+        miner_id,
+        TokenAmount::from_whole(1_000),
+        METHOD_SEND,
+        RawBytes::default(),
+    );
+    let v = v.with_epoch(200);
+    market_add_balance(&v, client, client, TokenAmount::from_whole(3));
+    let miner_collateral = TokenAmount::from_whole(64);
+    market_add_balance(&v, worker, miner_id, miner_collateral.clone());
+
+    // The client publishes a verified deal filling a sector, spending its datacap.
+    let deal_start = v.get_epoch() + max_prove_commit_duration(v.policy(), seal_proof).unwrap();
+    let deal_term_min = 180 * EPOCHS_IN_DAY;
+    let deal_ids = market_publish_deal(
+        &v,
+        worker,
+        client,
+        miner_id,
+        "deal1".to_string(),
+        PaddedPieceSize(deal_size),
+        true,
+        deal_start,
+        deal_term_min,
+    )
+    .ids;
+    assert_eq!(TokenAmount::zero(), datacap_get_balance(&v, client));
+
+    // The worker pre-commits and proves the sector, activating the deal and claiming the
+    // allocation.
+    let sector_term = deal_term_min + MARKET_DEFAULT_ALLOCATION_TERM_BUFFER;
+    miner_precommit_sector(
+        &v,
+        worker,
+        miner_id,
+        seal_proof,
+        sector_number,
+        deal_ids.clone(),
+        deal_start + sector_term,
+    );
+    let v = advance_by_deadline_to_epoch(v, miner_id, deal_start).0;
+    miner_prove_sector(&v, worker, miner_id, sector_number);
+    cron_tick(&v);
+
+    let market_state: MarketState = v.get_state(STORAGE_MARKET_ACTOR_ADDR).unwrap();
+    let deal_states = DealMetaArray::load(&market_state.states, v.store).unwrap();
+    let claim_id = deal_states.get(deal_ids[0]).unwrap().unwrap().verified_claim;
+    assert_ne!(0, claim_id);
+    let expected_claim = Claim {
+        provider: miner_id.id().unwrap(),
+        client: client.id().unwrap(),
+        data: make_piece_cid("deal1".as_bytes()),
+        size: PaddedPieceSize(deal_size),
+        term_min: deal_term_min,
+        term_max: deal_term_min + MARKET_DEFAULT_ALLOCATION_TERM_BUFFER,
+        term_start: deal_start,
+        sector: sector_number,
+    };
+    let verifreg_state: VerifregState = v.get_state(VERIFIED_REGISTRY_ACTOR_ADDR).unwrap();
+    let mut claims = verifreg_state.load_claims(v.store).unwrap();
+    assert_eq!(&expected_claim, claims.get(miner_id.id().unwrap(), claim_id).unwrap().unwrap());
+
+    // The sector gains verified power once its first window PoSt is accepted.
+    let (dline_info, p_idx, v) = advance_to_proving_deadline(v, miner_id, sector_number);
+    let expected_power =
+        PowerPair { raw: StoragePower::from(deal_size), qa: StoragePower::from(10 * deal_size) };
+    submit_windowed_post(&v, worker, miner_id, dline_info, p_idx, Some(expected_power.clone()));
+    assert_eq!(expected_power, miner_power(&v, miner_id));
+
+    // Let the market process the active deal before terminating the sector.
+    let deal_updates_interval = v.policy().deal_updates_interval;
+    let start = dline_info.close;
+    let v = v.with_epoch(start);
+    let v = advance_by_deadline_to_epoch_while_proving(
+        v,
+        miner_id,
+        worker,
+        sector_number,
+        start + deal_updates_interval,
+    );
+    let market_state: MarketState = v.get_state(STORAGE_MARKET_ACTOR_ADDR).unwrap();
+    let deal_states = DealMetaArray::load(&market_state.states, v.store).unwrap();
+    let deal_state = deal_states.get(deal_ids[0]).unwrap().unwrap();
+    assert!(deal_state.last_updated_epoch > 0);
+    assert_eq!(-1, deal_state.slash_epoch);
+
+    // The worker terminates the sector, which removes its power and pledge and slashes the deal.
+    let termination_epoch = v.get_epoch();
+    apply_ok(
+        &v,
+        worker,
+        miner_id,
+        TokenAmount::zero(),
+        MinerMethod::TerminateSectors as u64,
+        TerminateSectorsParams {
+            terminations: vec![TerminationDeclaration {
+                deadline: dline_info.index,
+                partition: p_idx,
+                sectors: make_bitfield(&[sector_number]),
+            }],
+        },
+    );
+    assert!(v.get_miner_balance(miner_id).initial_pledge.is_zero());
+    assert_eq!(PowerPair::zero(), miner_power(&v, miner_id));
+    let market_state: MarketState = v.get_state(STORAGE_MARKET_ACTOR_ADDR).unwrap();
+    let deal_states = DealMetaArray::load(&market_state.states, v.store).unwrap();
+    assert_eq!(termination_epoch, deal_states.get(deal_ids[0]).unwrap().unwrap().slash_epoch);
+
+    // Once market cron settles the slashed deal, it is removed from the market, and the client
+    // can withdraw its collateral.
+    let (v, _) =
+        advance_by_deadline_to_epoch(v, miner_id, termination_epoch + deal_updates_interval);
+    let market_state: MarketState = v.get_state(STORAGE_MARKET_ACTOR_ADDR).unwrap();
+    let proposals = DealArray::load(&market_state.proposals, v.store).unwrap();
+    assert!(proposals.get(deal_ids[0]).unwrap().is_none());
+    let deal_states = DealMetaArray::load(&market_state.states, v.store).unwrap();
+    assert!(deal_states.get(deal_ids[0]).unwrap().is_none());
+    let withdrawal = TokenAmount::from_whole(2);
+    apply_ok(
+        &v,
+        client,
+        STORAGE_MARKET_ACTOR_ADDR,
+        TokenAmount::zero(),
+        MarketMethod::WithdrawBalance as u64,
+        MarketWithdrawBalanceParams { provider_or_client: client, amount: withdrawal },
+    );
+
+    // The provider's collateral was slashed, and its remaining balance is paid to the multisig.
+    let msig_balance = v.get_actor(msig).unwrap().balance;
+    apply_ok(
+        &v,
+        worker,
+        STORAGE_MARKET_ACTOR_ADDR,
+        TokenAmount::zero(),
+        MarketMethod::WithdrawBalance as u64,
+        MarketWithdrawBalanceParams { provider_or_client: miner_id, amount: miner_collateral },
+    );
+    let withdrawn = v.get_actor(msig).unwrap().balance - msig_balance;
+    assert!(TokenAmount::from_whole(62) < withdrawn);
+    assert!(TokenAmount::from_whole(63) > withdrawn);
+
+    // The claim outlives the terminated sector, until it expires.
+    let verifreg_state: VerifregState = v.get_state(VERIFIED_REGISTRY_ACTOR_ADDR).unwrap();
+    let mut claims = verifreg_state.load_claims(v.store).unwrap();
+    assert_eq!(&expected_claim, claims.get(miner_id.id().unwrap(), claim_id).unwrap().unwrap());
+
+    v.expect_state_invariants(
+        &[invariant_failure_patterns::REWARD_STATE_EPOCH_MISMATCH.to_owned()],
+    );
+}
+
+fn create_msig(v: &VM, signers: Vec<Address>, threshold: u64) -> Address {
+    let msig_ctor_params = serialize(
+        &fil_actor_multisig::ConstructorParams {
+            signers: signers.clone(),
+            num_approvals_threshold: threshold,
+            unlock_duration: 0,
+            start_epoch: 0,
+        },
+        "multisig ctor params",
+    )
+    .unwrap();
+    let msig_ctor_ret: ExecReturn = apply_ok(
+        v,
+        signers[0],
+        INIT_ACTOR_ADDR,
+        TokenAmount::zero(),
+        fil_actor_init::Method::Exec as u64,
+        fil_actor_init::ExecParams {
+            code_cid: *MULTISIG_ACTOR_CODE_ID,
+            constructor_params: msig_ctor_params,
+        },
+    )
+    .deserialize()
+    .unwrap();
+    msig_ctor_ret.id_address
+}
@@ -0,0 +1,72 @@
+use fil_actor_init::ExecReturn;
+use fil_actor_paych::{ConstructorParams, Method as PaychMethod, State as PaychState};
+use fil_actors_runtime::cbor::serialize;
+use fil_actors_runtime::test_utils::PAYCH_ACTOR_CODE_ID;
+use fil_actors_runtime::INIT_ACTOR_ADDR;
+use fvm_ipld_blockstore::MemoryBlockstore;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::bigint::Zero;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::METHOD_SEND;
+use test_vm::util::{apply_code, apply_ok, create_accounts};
+use test_vm::VM;
+
+#[test]
+fn collect_deletes_channel_and_refunds_from() {
+    let store = MemoryBlockstore::new();
+    let v = VM::new_with_singletons(&store);
+    let start_balance = TokenAmount::from_whole(10_000);
+    let addrs = create_accounts(&v, 2, start_balance.clone());
+    let (from, to) = (addrs[0], addrs[1]);
+
+    // create a funded payment channel
+    let channel_funds = TokenAmount::from_whole(100);
+    let ctor_params =
+        serialize(&ConstructorParams { from, to }, "paych constructor params").unwrap();
+    let ret: ExecReturn = apply_ok(
+        &v,
+        from,
+        INIT_ACTOR_ADDR,
+        channel_funds.clone(),
+        fil_actor_init::Method::Exec as u64,
+        fil_actor_init::ExecParams {
+            code_cid: *PAYCH_ACTOR_CODE_ID,
+            constructor_params: ctor_params,
+        },
+    )
+    .deserialize()
+    .unwrap();
+    let paych = ret.id_address;
+    assert_eq!(channel_funds, v.get_actor(paych).unwrap().balance);
+
+    // settle with nothing redeemed and collect once settled
+    apply_ok(&v, to, paych, TokenAmount::zero(), PaychMethod::Settle as u64, RawBytes::default());
+    let st = v.get_state::<PaychState>(paych).unwrap();
+    let v = v.with_epoch(st.settling_at);
+    apply_ok(
+        &v,
+        from,
+        paych,
+        TokenAmount::zero(),
+        PaychMethod::Collect as u64,
+        RawBytes::default(),
+    );
+
+    // the channel is gone and its whole balance returned to from
+    assert!(v.get_actor(paych).is_none());
+    assert_eq!(start_balance, v.get_actor(from).unwrap().balance);
+    assert_eq!(start_balance, v.get_actor(to).unwrap().balance);
+
+    // the deleted channel's address no longer refers to an actor
+    apply_code(
+        &v,
+        from,
+        paych,
+        TokenAmount::from_atto(1),
+        METHOD_SEND,
+        RawBytes::default(),
+        ExitCode::SYS_INVALID_RECEIVER,
+    );
+    v.assert_state_invariants();
+}
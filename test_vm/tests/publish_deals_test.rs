@@ -151,7 +151,7 @@ fn psd_mistmatched_provider() {
     batcher.stage(a.client1, "deal2", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0, 2], good_inputs);
 
     v.assert_state_invariants();
@@ -173,7 +173,7 @@ fn psd_bad_piece_size() {
     batcher.stage(a.client1, "deal1", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![1], good_inputs);
     v.assert_state_invariants();
 }
@@ -193,7 +193,7 @@ fn psd_start_time_in_past() {
     batcher.stage(a.client1, "deal1", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![1], good_inputs);
     v.assert_state_invariants();
 }
@@ -209,7 +209,7 @@ fn psd_client_address_cannot_be_resolved() {
     batcher.stage(bad_client, "deal1", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0], good_inputs);
     v.assert_state_invariants();
 }
@@ -224,7 +224,7 @@ fn psd_no_client_lockup() {
     batcher.stage(a.client1, "deal1", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![1], good_inputs);
     v.assert_state_invariants();
 }
@@ -254,7 +254,7 @@ fn psd_not_enought_client_lockup_for_batch() {
     batcher.stage(a.cheap_client, "deal2", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0], good_inputs);
     v.assert_state_invariants();
 }
@@ -297,7 +297,7 @@ fn psd_not_enough_provider_lockup_for_batch() {
     // bad deal insufficient funds on provider
     batcher.stage(a.client2, "deal1", DealOptions::default());
     let deal_ret = batcher.publish_ok(cheap_worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0], good_inputs);
     v.assert_state_invariants();
 }
@@ -324,7 +324,7 @@ fn psd_duplicate_deal_in_batch() {
     batcher.stage(a.client1, "deal1", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0, 1, 4], good_inputs);
     v.assert_state_invariants();
 }
@@ -338,7 +338,7 @@ fn psd_duplicate_deal_in_state() {
 
     batcher.stage(a.client2, "deal0", DealOptions::default());
     let deal_ret1 = batcher.publish_ok(a.worker);
-    let good_inputs1 = bf_all(deal_ret1.valid_deals);
+    let good_inputs1 = bf_all(&v, deal_ret1.valid_deals);
     assert_eq!(vec![0], good_inputs1);
 
     let mut batcher =
@@ -350,7 +350,7 @@ fn psd_duplicate_deal_in_state() {
     batcher.stage(a.client2, "deal0", DealOptions::default());
 
     let deal_ret2 = batcher.publish_ok(a.worker);
-    let good_inputs2 = bf_all(deal_ret2.valid_deals);
+    let good_inputs2 = bf_all(&v, deal_ret2.valid_deals);
     assert_eq!(vec![0], good_inputs2);
     v.assert_state_invariants();
 }
@@ -385,7 +385,7 @@ fn psd_verified_deal_fails_getting_datacap() {
     );
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0, 1], good_inputs);
     v.assert_state_invariants();
 }
@@ -454,7 +454,7 @@ fn psd_random_assortment_of_failures() {
     batcher.stage(a.client1, "deal8", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0, 2, 8], good_inputs);
     v.assert_state_invariants();
 }
@@ -590,7 +590,7 @@ fn psd_all_deals_are_good() {
     batcher.stage(a.client1, "deal4", DealOptions::default());
 
     let deal_ret = batcher.publish_ok(a.worker);
-    let good_inputs = bf_all(deal_ret.valid_deals);
+    let good_inputs = bf_all(&v, deal_ret.valid_deals);
     assert_eq!(vec![0, 1, 2, 3, 4], good_inputs);
     v.assert_state_invariants();
 }
@@ -637,7 +637,7 @@ fn bad_post_upgrade_dispute() {
     )
     .deserialize()
     .unwrap();
-    assert_eq!(vec![100], bf_all(updated_sectors));
+    assert_eq!(vec![100], bf_all(&v, updated_sectors));
 
     // sanity check the sector after update
     let new_sector_info = sector_info(&v, maddr, sector_number);
@@ -1050,7 +1050,7 @@ fn replica_update_verified_deal() {
     )
     .deserialize()
     .unwrap();
-    assert_eq!(vec![100], bf_all(updated_sectors));
+    assert_eq!(vec![100], bf_all(&v, updated_sectors));
 
     let old_power = power_for_sector(seal_proof.sector_size().unwrap(), &old_sector_info);
     let expected_update_claimed_power_params = UpdateClaimedPowerParams {
@@ -1244,7 +1244,7 @@ fn create_miner_and_upgrade_sector(
     }
     .deserialize()
     .unwrap();
-    assert_eq!(vec![100], bf_all(updated_sectors));
+    assert_eq!(vec![100], bf_all(&v, updated_sectors));
 
     // sanity check the sector after update
     let new_sector_info = sector_info(&v, maddr, sector_number);
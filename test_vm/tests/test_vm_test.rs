@@ -1,13 +1,23 @@
 use fil_actor_account::State as AccountState;
+use fil_actor_init::{ExecParams, ExecReturn, Method as InitMethod};
+use fil_actor_paych::{
+    ConstructorParams as PaychConstructorParams, Method as PaychMethod, State as PaychState,
+};
+use fil_actor_power::{CreateMinerParams, Method as PowerMethod};
+use fil_actors_runtime::cbor::serialize;
+use fil_actors_runtime::runtime::Policy;
 use fil_actors_runtime::test_utils::{make_builtin, ACCOUNT_ACTOR_CODE_ID, PAYCH_ACTOR_CODE_ID};
+use fil_actors_runtime::{INIT_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR};
 use fvm_ipld_blockstore::MemoryBlockstore;
-use fvm_ipld_encoding::RawBytes;
+use fvm_ipld_encoding::{BytesDe, RawBytes};
 use fvm_shared::address::Address;
 use fvm_shared::econ::TokenAmount;
 use fvm_shared::error::ExitCode;
+use fvm_shared::sector::RegisteredPoStProof;
+use fvm_shared::version::NetworkVersion;
 use fvm_shared::METHOD_SEND;
 use num_traits::Zero;
-use test_vm::util::pk_addrs_from;
+use test_vm::util::{apply_ok, create_accounts, pk_addrs_from};
 use test_vm::{actor, FIRST_TEST_USER_ADDR, TEST_FAUCET_ADDR, VM};
 
 #[test]
@@ -40,6 +50,171 @@ fn state_control() {
     assert!(invariants_check.unwrap_err().to_string().contains("AccountState is empty"));
 }
 
+#[test]
+fn fork_isolates_state() {
+    let store = MemoryBlockstore::new();
+    let v = VM::new_with_singletons(&store);
+    let base = v.checkpoint();
+    let addr = Address::new_bls(&[1; fvm_shared::address::BLS_PUB_LEN]).unwrap();
+
+    // a send applied in a fork is not visible in the original
+    let fork = v.fork();
+    fork.apply_message(
+        TEST_FAUCET_ADDR,
+        addr,
+        TokenAmount::from_atto(42u8),
+        METHOD_SEND,
+        RawBytes::default(),
+    )
+    .unwrap();
+    assert!(fork.normalize_address(&addr).is_some());
+    assert!(v.normalize_address(&addr).is_none());
+    assert_eq!(base, v.checkpoint());
+
+    // a second fork from the same root diverges independently
+    let other = v.fork_at(base);
+    other
+        .apply_message(
+            TEST_FAUCET_ADDR,
+            addr,
+            TokenAmount::from_atto(7u8),
+            METHOD_SEND,
+            RawBytes::default(),
+        )
+        .unwrap();
+    let id = other.normalize_address(&addr).unwrap();
+    assert_eq!(TokenAmount::from_atto(7u8), other.get_actor(id).unwrap().balance);
+    assert_eq!(TokenAmount::from_atto(42u8), fork.get_actor(id).unwrap().balance);
+}
+
+#[test]
+fn network_version_and_policy_are_configurable() {
+    let store = MemoryBlockstore::new();
+    let mut policy = Policy::default();
+    policy.valid_post_proof_type.remove(&RegisteredPoStProof::StackedDRGWindow32GiBV1);
+    let v = VM::new_with_singletons(&store)
+        .with_network_version(NetworkVersion::V17)
+        .with_policy(policy.clone());
+    assert_eq!(NetworkVersion::V17, v.network_version());
+
+    // Actors see the overridden policy.
+    let owner = create_accounts(&v, 1, TokenAmount::from_whole(10_000))[0];
+    let params = CreateMinerParams {
+        owner,
+        worker: owner,
+        window_post_proof_type: RegisteredPoStProof::StackedDRGWindow32GiBV1,
+        peer: "miner".as_bytes().to_vec(),
+        multiaddrs: vec![BytesDe("multiaddr".as_bytes().to_vec())],
+    };
+    let res = v
+        .apply_message(
+            owner,
+            STORAGE_POWER_ACTOR_ADDR,
+            TokenAmount::zero(),
+            PowerMethod::CreateMiner as u64,
+            params,
+        )
+        .unwrap();
+    assert_eq!(ExitCode::USR_ILLEGAL_ARGUMENT, res.code);
+
+    // The configuration survives advancing the epoch, and the version can change mid-test.
+    let v = v.with_epoch(100);
+    assert_eq!(&policy, v.policy());
+    let v = v.with_network_version(NetworkVersion::V16);
+    assert_eq!(NetworkVersion::V16, v.network_version());
+    assert_eq!(&policy, v.policy());
+    v.assert_state_invariants();
+}
+
+#[test]
+fn read_only_messages_cannot_change_state() {
+    let store = MemoryBlockstore::new();
+    let v = VM::new_with_singletons(&store);
+    let start_balance = TokenAmount::from_whole(10_000);
+    let addrs = create_accounts(&v, 2, start_balance.clone());
+    let (from, to) = (addrs[0], addrs[1]);
+    let ctor_params =
+        serialize(&PaychConstructorParams { from, to }, "paych constructor params").unwrap();
+    let exec_params =
+        || ExecParams { code_cid: *PAYCH_ACTOR_CODE_ID, constructor_params: ctor_params.clone() };
+
+    // Actors can't be created.
+    let res = v
+        .apply_message_read_only(
+            from,
+            INIT_ACTOR_ADDR,
+            TokenAmount::zero(),
+            InitMethod::Exec as u64,
+            exec_params(),
+        )
+        .unwrap();
+    assert_eq!(ExitCode::USR_FORBIDDEN, res.code);
+    let res = v
+        .apply_message_read_only(
+            from,
+            Address::new_bls(&[7; fvm_shared::address::BLS_PUB_LEN]).unwrap(),
+            TokenAmount::zero(),
+            METHOD_SEND,
+            RawBytes::default(),
+        )
+        .unwrap();
+    assert_eq!(ExitCode::USR_FORBIDDEN, res.code);
+
+    // Value can't be transferred.
+    let res = v
+        .apply_message_read_only(
+            from,
+            to,
+            TokenAmount::from_atto(1),
+            METHOD_SEND,
+            RawBytes::default(),
+        )
+        .unwrap();
+    assert_eq!(ExitCode::USR_FORBIDDEN, res.code);
+    assert_eq!(start_balance, v.get_actor(to).unwrap().balance);
+
+    // Actors can't be deleted. Collecting a settled channel with nothing redeemed sends
+    // nothing to the payee before deleting the channel.
+    let ret: ExecReturn = apply_ok(
+        &v,
+        from,
+        INIT_ACTOR_ADDR,
+        TokenAmount::from_whole(100),
+        InitMethod::Exec as u64,
+        exec_params(),
+    )
+    .deserialize()
+    .unwrap();
+    let paych = ret.id_address;
+    apply_ok(&v, to, paych, TokenAmount::zero(), PaychMethod::Settle as u64, RawBytes::default());
+    let st = v.get_state::<PaychState>(paych).unwrap();
+    let v = v.with_epoch(st.settling_at);
+    let res = v
+        .apply_message_read_only(
+            from,
+            paych,
+            TokenAmount::zero(),
+            PaychMethod::Collect as u64,
+            RawBytes::default(),
+        )
+        .unwrap();
+    assert_eq!(ExitCode::USR_FORBIDDEN, res.code);
+    assert_eq!(TokenAmount::from_whole(100), v.get_actor(paych).unwrap().balance);
+
+    // Reads are still allowed.
+    let res = v
+        .apply_message_read_only(
+            from,
+            from,
+            TokenAmount::zero(),
+            fil_actor_account::Method::PubkeyAddress as u64,
+            RawBytes::default(),
+        )
+        .unwrap();
+    assert_eq!(ExitCode::OK, res.code);
+    v.assert_state_invariants();
+}
+
 fn assert_account_actor(
     exp_call_seq: u64,
     exp_bal: TokenAmount,